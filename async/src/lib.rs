@@ -0,0 +1,129 @@
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::future::{self, Either};
+use futures::task::AtomicWaker;
+use futures::{SinkExt, Stream, StreamExt};
+use gol_lib::Field;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// Generations of a strategy, computed ahead on a separate thread.
+///
+/// The strategy is advanced on its own thread, so blocking strategies like `gol_conc::Strategy`
+/// never block the executor. At most `buffer` generations are computed ahead of the consumer.
+/// Dropping the stream or cancelling it ends the stream right away and stops the strategy
+/// after the generation in progress.
+pub struct Generations {
+    receiver: mpsc::Receiver<Field>,
+    cancellation: Arc<Cancellation>,
+}
+
+/// Cancellation shared by the stream, its producer and their [`CancelHandle`]s.
+#[derive(Debug, Default)]
+struct Cancellation {
+    cancelled: AtomicBool,
+    /// Consumer waiting for the next generation.
+    consumer: AtomicWaker,
+    /// Producer waiting for space in the buffer.
+    producer: AtomicWaker,
+}
+
+impl Cancellation {
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.consumer.wake();
+        self.producer.wake();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Cancels a [`Generations`] stream from anywhere.
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    cancellation: Arc<Cancellation>,
+}
+
+impl CancelHandle {
+    /// Ends the stream, waking up a consumer waiting for the next generation.
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+}
+
+impl Generations {
+    pub fn spawn<S>(strategy: S, buffer: usize) -> Self
+    where
+        S: Iterator<Item = Field> + Send + 'static,
+    {
+        // The channel holds one message per sender on top of its buffer. A send waiting for
+        // space has already handed over its message, so `buffer` generations are computed ahead.
+        let (mut sender, receiver) = mpsc::channel(buffer.max(1) - 1);
+        let cancellation = Arc::new(Cancellation::default());
+
+        let producer = Arc::clone(&cancellation);
+        std::thread::spawn(move || {
+            for field in strategy {
+                let cancelled = future::poll_fn(|cx| {
+                    producer.producer.register(cx.waker());
+                    if producer.is_cancelled() {
+                        Poll::Ready(())
+                    } else {
+                        Poll::Pending
+                    }
+                });
+                // A full buffer mustn't keep the producer from noticing the cancellation.
+                match block_on(future::select(sender.send(field), Box::pin(cancelled))) {
+                    Either::Left((Ok(()), _)) => {}
+                    Either::Left((Err(_), _)) | Either::Right(_) => break,
+                }
+            }
+        });
+
+        Generations {
+            receiver,
+            cancellation,
+        }
+    }
+
+    pub fn cancel_handle(&self) -> CancelHandle {
+        CancelHandle {
+            cancellation: Arc::clone(&self.cancellation),
+        }
+    }
+
+    /// Waits for the next generation, `None` once the strategy finished or was cancelled.
+    pub async fn step(&mut self) -> Option<Field> {
+        self.next().await
+    }
+}
+
+impl Stream for Generations {
+    type Item = Field;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Registered before checking, so a cancellation in between still wakes the consumer.
+        self.cancellation.consumer.register(cx.waker());
+        if self.cancellation.is_cancelled() {
+            // Wakes up the producer if it waits for space in the buffer.
+            self.receiver.close();
+            return Poll::Ready(None);
+        }
+        self.receiver.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for Generations {
+    fn drop(&mut self) {
+        // Don't wait for the producer, it may be in the middle of a long generation.
+        self.cancellation.cancel();
+    }
+}
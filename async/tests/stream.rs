@@ -0,0 +1,101 @@
+use futures::StreamExt;
+use gol_async::Generations;
+use gol_lib::Field;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_matches_iterator() {
+    let field = Field::random_seeded(30, 30, 3).unwrap();
+    let expected = gol_naive::Strategy::new(field.clone())
+        .take(40)
+        .collect::<Vec<_>>();
+
+    let generations = Generations::spawn(gol_naive::Strategy::new(field), 4);
+    assert_eq!(generations.take(40).collect::<Vec<_>>().await, expected);
+}
+
+#[tokio::test]
+async fn test_conc_step() {
+    let field = Field::random_seeded(30, 30, 3).unwrap();
+    let expected = gol_naive::Strategy::new(field.clone()).nth(4);
+
+    let mut generations = Generations::spawn(gol_conc::Strategy::new(field), 2);
+    for _ in 0..4 {
+        assert!(generations.step().await.is_some());
+    }
+    assert_eq!(generations.step().await, expected);
+}
+
+#[tokio::test]
+async fn test_bounded_buffer() {
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&produced);
+    let strategy = std::iter::repeat_with(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Field::dead(3, 3)
+    });
+
+    let mut generations = Generations::spawn(strategy, 3);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    // The producer waits for space once the buffer is full.
+    assert_eq!(produced.load(Ordering::SeqCst), 3);
+
+    generations.step().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(produced.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_cancel() {
+    let field = Field::random_seeded(30, 30, 3).unwrap();
+    let mut generations = Generations::spawn(gol_naive::Strategy::new(field), 2);
+    let handle = generations.cancel_handle();
+
+    assert!(generations.step().await.is_some());
+    handle.cancel();
+    assert!(handle.is_cancelled());
+    assert_eq!(generations.step().await, None);
+}
+
+#[tokio::test]
+async fn test_cancel_pending() {
+    // The first generation takes far longer than the test may wait.
+    let strategy = std::iter::repeat_with(|| {
+        std::thread::sleep(Duration::from_secs(30));
+        Field::dead(3, 3)
+    });
+    let mut generations = Generations::spawn(strategy, 2);
+    let handle = generations.cancel_handle();
+
+    let canceller = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.cancel();
+    });
+    let step = tokio::time::timeout(Duration::from_secs(5), generations.step()).await;
+    assert_eq!(step, Ok(None));
+    canceller.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_cancel_full_buffer() {
+    let alive = Arc::new(());
+    let producer = Arc::clone(&alive);
+    let strategy = std::iter::repeat_with(move || {
+        let _ = &producer;
+        Field::dead(3, 3)
+    });
+    let generations = Generations::spawn(strategy, 1);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // The producer waits for space, cancelling has to wake it up so it drops the strategy.
+    generations.cancel_handle().cancel();
+    for _ in 0..100 {
+        if Arc::strong_count(&alive) == 1 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("producer still waits for space after cancelling");
+}
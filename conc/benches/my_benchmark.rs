@@ -1,6 +1,7 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use gol_conc::Strategy;
 use gol_lib::Field;
+use gol_rayon::Strategy as RayonStrategy;
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("gol-conc 1000 1000", |b| {
@@ -27,7 +28,33 @@ fn criterion_benchmark(c: &mut Criterion) {
         let mut strategy = Strategy::new(field);
         b.iter(|| strategy.next());
     });
+    // Batches are advanced at once, so both take 8 generations per iteration.
+    c.bench_function("gol-conc 1000 1000 8 generations", |b| {
+        let field = Field::random(1000, 1000);
+
+        let mut strategy = Strategy::new(field);
+        b.iter(|| strategy.nth(7));
+    });
+    c.bench_function("gol-conc 1000 1000 8 generations batched", |b| {
+        let field = Field::random(1000, 1000);
+
+        let mut strategy = Strategy::new(field);
+        strategy.set_batch(8);
+        b.iter(|| strategy.nth(7));
+    });
+}
+
+/// The rayon strategy on the same fields as the work-stealing one above.
+fn rayon_benchmark(c: &mut Criterion) {
+    for (width, height) in [(1000, 1000), (500, 500), (1000, 500), (500, 1000)] {
+        c.bench_function(&format!("gol-rayon {} {}", width, height), |b| {
+            let field = Field::random(width, height);
+
+            let mut strategy = RayonStrategy::new(field);
+            b.iter(|| strategy.next());
+        });
+    }
 }
 
-criterion_group!(benches, criterion_benchmark);
+criterion_group!(benches, criterion_benchmark, rayon_benchmark);
 criterion_main!(benches);
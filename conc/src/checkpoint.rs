@@ -0,0 +1,85 @@
+use fnv::FnvHasher;
+use gol_lib::{Field, Snapshot, State};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::hash::Hasher;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// State of a run, including the hashes of the recently visited fields to detect cycles.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub snapshot: Snapshot,
+    pub visited: HashSet<u64>,
+}
+
+impl Checkpoint {
+    /// Most hashes kept in [`Checkpoint::visited`]. Once reached they are forgotten, so only
+    /// cycles of up to this many generations are detected.
+    pub const MAX_VISITED: usize = 100_000;
+
+    pub fn new(snapshot: Snapshot) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(Self::hash(&snapshot.field));
+        Checkpoint { snapshot, visited }
+    }
+
+    /// Hashes the size and the state of every cell with FNV-1a, whose output is fixed by its
+    /// specification, so hashes saved by one build still match those of another.
+    pub fn hash(field: &Field) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hasher.write(&(field.width() as u64).to_le_bytes());
+        hasher.write(&(field.height() as u64).to_le_bytes());
+        for y in 0..field.height() {
+            for x in 0..field.width() {
+                hasher.write_u8(State::from(*field.value((x, y))).0);
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Records the next generation. Returns `false` if the field was visited before.
+    pub fn advance(&mut self, field: Field) -> bool {
+        let hash = Self::hash(&field);
+        if self.visited.len() >= Self::MAX_VISITED && !self.visited.contains(&hash) {
+            self.visited.clear();
+        }
+        let unvisited = self.visited.insert(hash);
+        self.snapshot.field = field;
+        self.snapshot.generation += 1;
+        unvisited
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Writes the checkpoint to a temporary file next to `path` and renames it afterwards,
+    /// so an interrupted write never replaces the previous checkpoint with a partial one.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut temporary = OsString::from(path.as_os_str());
+        temporary.push(".tmp");
+
+        let mut writer = BufWriter::new(File::create(&temporary)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        drop(writer);
+
+        fs::rename(&temporary, path)?;
+
+        // Persist the rename itself.
+        #[cfg(unix)]
+        {
+            let parent = match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+            File::open(parent)?.sync_all()?;
+        }
+        Ok(())
+    }
+}
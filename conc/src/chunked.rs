@@ -0,0 +1,114 @@
+use crate::fault::Faults;
+use crate::scheduler::{Reply, Scheduler};
+use crate::{StepError, Task, DEFAULT_DEADLINE};
+use arc_swap::ArcSwap;
+use crossbeam_deque::Injector;
+use gol_lib::{Chunk, ChunkedField, GolError, Rule, Topology};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+type ChunkReply = Reply<(i64, i64), ((i64, i64), Option<Chunk>)>;
+
+/// Advances a [`ChunkedField`] with one task per active chunk.
+pub struct ChunkedStrategy {
+    injector: Arc<Injector<Task<(i64, i64)>>>,
+    field: Arc<ArcSwap<ChunkedField>>,
+    scheduler: Scheduler,
+    worker_output: mpsc::Receiver<ChunkReply>,
+    deadline: Duration,
+    /// Number of attempts at advancing a generation so far.
+    epoch: u64,
+}
+
+impl ChunkedStrategy {
+    pub fn new(field: ChunkedField) -> Self {
+        Self::spawn(field, Rule::default())
+    }
+
+    /// Fails for rules which aren't supported on an unbounded field, see
+    /// [`Rule::check_unbounded`].
+    pub fn with_rule(field: ChunkedField, rule: Rule) -> Result<Self, GolError> {
+        rule.check_unbounded()?;
+        Ok(Self::spawn(field, rule))
+    }
+
+    fn spawn(field: ChunkedField, rule: Rule) -> Self {
+        let worker_input = Arc::new(Injector::new());
+        let field = Arc::new(ArcSwap::from_pointee(field));
+        let (sender, receiver) = mpsc::sync_channel(1000);
+
+        let scheduler = Scheduler::new(
+            Arc::clone(&worker_input),
+            Arc::clone(&field),
+            sender,
+            rule,
+            Topology::default(),
+            Faults::default(),
+        );
+
+        ChunkedStrategy {
+            injector: worker_input,
+            field,
+            scheduler,
+            worker_output: receiver,
+            deadline: DEFAULT_DEADLINE,
+            epoch: 0,
+        }
+    }
+
+    /// Sets the time the workers get to advance all chunks of a generation.
+    pub fn set_deadline(&mut self, deadline: Duration) {
+        self.deadline = deadline;
+    }
+
+    /// Advances the field by one generation, see [`Strategy::try_next`](crate::Strategy::try_next).
+    pub fn try_next(&mut self) -> Result<Option<ChunkedField>, StepError> {
+        for worker in self.scheduler.respawn_finished() {
+            eprintln!("Replaced worker #{} which panicked", worker);
+        }
+
+        let active = self.field.load().active_chunks();
+
+        self.epoch += 1;
+        let outputs = self.scheduler.advance(
+            &self.injector,
+            &self.worker_output,
+            self.epoch,
+            active,
+            self.deadline,
+        )?;
+        let chunks = outputs
+            .into_iter()
+            .filter_map(|(key, chunk)| Some((key, chunk?)));
+        let field = ChunkedField::from_chunks(chunks);
+
+        if **self.field.load() == field {
+            return Ok(None);
+        }
+        self.field.store(Arc::new(field.clone()));
+
+        Ok(Some(field))
+    }
+}
+
+impl Iterator for ChunkedStrategy {
+    type Item = ChunkedField;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.try_next() {
+            Ok(field) => field,
+            Err(why) => {
+                eprintln!("Failed to advance generation: {}", why);
+                None
+            }
+        }
+    }
+}
+
+impl Drop for ChunkedStrategy {
+    fn drop(&mut self) {
+        for _ in 0..self.scheduler.worker_count() {
+            self.injector.push(Task::Stop);
+        }
+    }
+}
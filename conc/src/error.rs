@@ -0,0 +1,64 @@
+use gol_lib::GolError;
+use std::error::Error;
+use std::fmt;
+
+/// Reasons a generation couldn't be advanced.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum StepError {
+    /// Not all parts were advanced before the deadline.
+    Timeout { received: usize, expected: usize },
+    /// The worker panicked while advancing a part and was replaced.
+    WorkerPanicked { worker: usize },
+    /// A part was answered twice or was never requested.
+    Duplicate { part: Region },
+    /// A worker failed to advance its part.
+    Advance(GolError),
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepError::Timeout { received, expected } => write!(
+                f,
+                "only {} of {} parts were advanced before the deadline",
+                received, expected
+            ),
+            StepError::WorkerPanicked { worker } => write!(f, "worker #{} panicked", worker),
+            StepError::Duplicate { part } => write!(f, "{} was advanced twice", part),
+            StepError::Advance(why) => write!(f, "failed to advance part: {}", why),
+        }
+    }
+}
+
+/// Part of a field which a worker advances.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Region {
+    /// Rows from `start` up to but excluding `end` of a [`Field`](gol_lib::Field).
+    Rows { start: usize, end: usize },
+    /// Chunk with the given key of a [`ChunkedField`](gol_lib::ChunkedField).
+    Chunk { key: (i64, i64) },
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Region::Rows { start, end } => write!(f, "band of rows {} to {}", start, end),
+            Region::Chunk { key: (x, y) } => write!(f, "chunk ({}, {})", x, y),
+        }
+    }
+}
+
+impl Error for StepError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StepError::Advance(why) => Some(why),
+            _ => None,
+        }
+    }
+}
+
+impl From<GolError> for StepError {
+    fn from(error: GolError) -> Self {
+        StepError::Advance(error)
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "fault-injection")]
+use std::collections::VecDeque;
+#[cfg(feature = "fault-injection")]
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+/// Faults to inject into workers to test failure handling.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Fault {
+    /// The worker panics instead of advancing its next part.
+    Panic,
+    /// The worker waits before advancing its next part.
+    Delay(Duration),
+}
+
+/// Faults pending to be applied by the next workers picking up a part.
+///
+/// Does nothing unless the `fault-injection` feature is enabled.
+#[derive(Debug, Clone, Default)]
+pub struct Faults {
+    #[cfg(feature = "fault-injection")]
+    pending: Arc<Mutex<VecDeque<Fault>>>,
+}
+
+impl Faults {
+    #[cfg(feature = "fault-injection")]
+    pub fn inject(&self, fault: Fault) {
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_back(fault);
+    }
+
+    pub fn apply(&self, worker: usize) {
+        #[cfg(feature = "fault-injection")]
+        {
+            let fault = self
+                .pending
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .pop_front();
+            match fault {
+                Some(Fault::Panic) => panic!("Injected fault in worker #{}", worker),
+                Some(Fault::Delay(delay)) => std::thread::sleep(delay),
+                None => {}
+            }
+        }
+        #[cfg(not(feature = "fault-injection"))]
+        let _ = worker;
+    }
+}
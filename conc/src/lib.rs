@@ -1,93 +1,200 @@
-use crate::scheduler::Scheduler;
+use crate::fault::Faults;
+use crate::scheduler::{Band, Reply, Scheduler};
+use arc_swap::ArcSwap;
 use crossbeam_deque::Injector;
-use gol_lib::Field;
-use std::sync::mpsc::RecvTimeoutError;
-use std::sync::{mpsc, Arc, RwLock};
+use gol_lib::{Activity, Field, Rule, Topology};
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
 
+mod checkpoint;
+mod chunked;
+mod error;
+mod fault;
 mod scheduler;
 
-pub enum Task {
-    Row(usize),
+pub use checkpoint::Checkpoint;
+pub use chunked::ChunkedStrategy;
+pub use error::{Region, StepError};
+pub use fault::Fault;
+pub use gol_lib::Update;
+
+/// Time the workers get to advance all parts of a generation unless configured otherwise.
+pub const DEFAULT_DEADLINE: Duration = Duration::from_secs(10);
+
+pub enum Task<P> {
+    /// Advances one part of the field, a band of rows of a [`Field`] or a chunk of a
+    /// [`ChunkedField`](gol_lib::ChunkedField), for the attempt at a generation with the
+    /// given epoch.
+    Advance {
+        epoch: u64,
+        part: P,
+    },
     Stop,
 }
 
-pub type Update = ((usize, usize), char);
-
 pub struct Strategy {
-    injector: Arc<Injector<Task>>,
-    field: Arc<RwLock<Field>>,
+    injector: Arc<Injector<Task<Band>>>,
+    /// Latest generation, read by the workers without locking.
+    field: Arc<ArcSwap<Field>>,
     scheduler: Scheduler,
-    worker_output: mpsc::Receiver<Vec<Update>>,
+    worker_output: mpsc::Receiver<Reply<Band, Vec<Vec<Update>>>>,
+    activity: Activity,
+    topology: Topology,
+    /// Rows a change spreads by each generation.
+    range: usize,
+    /// Generations the workers advance their bands by between synchronisations.
+    batch: usize,
+    /// Generations advanced ahead of those returned so far.
+    pending: VecDeque<Field>,
+    deadline: Duration,
+    /// Number of attempts at advancing a generation so far.
+    epoch: u64,
+    #[cfg_attr(not(feature = "fault-injection"), allow(dead_code))]
+    faults: Faults,
 }
 
 impl Strategy {
     pub fn new(field: Field) -> Self {
-        let worker_input = Arc::new(Injector::<Task>::new());
-        let field = Arc::new(RwLock::new(field));
+        Self::with_rule(field, Rule::default(), Topology::default())
+    }
+
+    pub fn with_rule(field: Field, rule: Rule, topology: Topology) -> Self {
+        let worker_input = Arc::new(Injector::new());
+        let range = rule.neighbourhood().range();
+        let activity = Activity::with_range(field.width(), field.height(), topology, range);
+        let field = Arc::new(ArcSwap::from_pointee(field));
         let (sender, receiver) = mpsc::sync_channel(1000);
+        let faults = Faults::default();
 
-        let scheduler = Scheduler::new(Arc::clone(&worker_input), Arc::clone(&field), sender);
+        let scheduler = Scheduler::new(
+            Arc::clone(&worker_input),
+            Arc::clone(&field),
+            sender,
+            rule,
+            topology,
+            faults.clone(),
+        );
 
         Strategy {
             injector: worker_input,
             field,
             scheduler,
             worker_output: receiver,
+            activity,
+            topology,
+            range,
+            batch: 1,
+            pending: VecDeque::new(),
+            deadline: DEFAULT_DEADLINE,
+            epoch: 0,
+            faults,
         }
     }
-}
 
-impl Iterator for Strategy {
-    type Item = Field;
+    /// Sets the time the workers get to advance all rows of a generation, or a batch of
+    /// generations.
+    pub fn set_deadline(&mut self, deadline: Duration) {
+        self.deadline = deadline;
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut field = match self.field.read() {
-            Err(why) => {
-                panic!("Failed to get read lock on field: {:?}", why);
-            }
-            Ok(lock) => Field::clone(&lock),
-        };
+    /// Lets the workers advance their bands of rows by the given number of generations before
+    /// synchronising, with halos of as many rows around each band, times the range of the
+    /// rule's neighbourhood.
+    ///
+    /// Batching trades redundant work on the halos for fewer round trips through the
+    /// coordinator; the generations returned are the same either way.
+    pub fn set_batch(&mut self, generations: usize) {
+        self.batch = generations.max(1);
+    }
+
+    /// Makes the next worker picking up a band fail in the given way.
+    #[cfg(feature = "fault-injection")]
+    pub fn inject_fault(&self, fault: Fault) {
+        self.faults.inject(fault);
+    }
+
+    /// Advances the field by one generation, `None` if no cell changed.
+    ///
+    /// On error the field keeps its previous generation, and workers which panicked are
+    /// replaced.
+    pub fn try_next(&mut self) -> Result<Option<Field>, StepError> {
+        if let Some(field) = self.pending.pop_front() {
+            return Ok(Some(field));
+        }
+        let mut field = Field::clone(&self.field.load());
 
-        for row in 0..field.height() {
-            self.injector.push(Task::Row(row));
+        for worker in self.scheduler.respawn_finished() {
+            eprintln!("Replaced worker #{} which panicked", worker);
         }
 
-        let mut updated_any = false;
-        let mut received_results_from = 0;
-        while received_results_from < field.height() {
-            let updates = match self
-                .worker_output
-                .recv_timeout(std::time::Duration::from_secs(1))
-            {
-                Err(RecvTimeoutError::Disconnected) => {
-                    eprintln!("Channel closed abruptly");
-                    Vec::new()
-                }
-                Err(RecvTimeoutError::Timeout) => {
-                    eprintln!("Response took to long");
-                    Vec::new()
-                }
-                Ok(result) => result,
-            };
-            received_results_from += 1;
-            for (cords, value) in updates {
+        // Single generations are advanced row by row, batches need bands much higher than
+        // their halos to be worth it.
+        let height = field.height();
+        let band_height = match self.batch {
+            1 => 1,
+            batch => (height / (self.scheduler.worker_count() * 4)).max(2 * batch * self.range),
+        };
+        // Bands without changes around them last generation won't change either. Changes
+        // spread by the range of the neighbourhood each generation of the batch.
+        let (activity, topology, batch) = (&self.activity, self.topology, self.batch);
+        let halo = (batch - 1) * self.range;
+        let active_bands = (0..height)
+            .step_by(band_height)
+            .map(|start| Band {
+                start,
+                end: (start + band_height).min(height),
+                generations: batch,
+            })
+            .filter(|band| {
+                let (first, count) = band.with_halo(halo, height, topology);
+                (first..first + count).any(|row| activity.is_row_active(row % height))
+            });
+
+        self.epoch += 1;
+        let outputs = self.scheduler.advance(
+            &self.injector,
+            &self.worker_output,
+            self.epoch,
+            active_bands,
+            self.deadline,
+        )?;
+
+        let mut generations = vec![Vec::new(); self.batch];
+        for output in outputs {
+            for (updates, band_updates) in generations.iter_mut().zip(output) {
+                updates.extend(band_updates);
+            }
+        }
+        let mut last_updates = Vec::new();
+        for updates in generations {
+            last_updates = updates;
+            if last_updates.is_empty() {
+                break;
+            }
+            for &(cords, value) in &last_updates {
                 *field.value_mut(cords) = value;
-                updated_any = true;
             }
+            self.pending.push_back(field.clone());
         }
+        self.activity.record(&last_updates);
+        self.field.store(Arc::new(field));
 
-        if !updated_any {
-            return None;
-        }
+        Ok(self.pending.pop_front())
+    }
+}
+
+impl Iterator for Strategy {
+    type Item = Field;
 
-        match self.field.write() {
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.try_next() {
+            Ok(field) => field,
             Err(why) => {
-                panic!("Failed to get write lock on field: {:?}", why);
+                eprintln!("Failed to advance generation: {}", why);
+                None
             }
-            Ok(mut lock) => *lock = field.clone(),
-        };
-
-        Some(field)
+        }
     }
 }
 
@@ -1,21 +1,31 @@
-use gol_conc::Strategy;
-use gol_lib::Field;
-use std::collections::hash_map::DefaultHasher;
-use std::collections::HashSet;
+use gol_conc::{Checkpoint, Strategy};
+use gol_lib::{Field, Snapshot};
 use std::convert::TryFrom;
-use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
-fn hash(field: &Field) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    field.hash(&mut hasher);
-    hasher.finish()
-}
-
 fn main() {
-    let mut args = std::env::args();
-    args.next();
+    let mut resume: Option<PathBuf> = None;
+    let mut checkpoint_path: Option<PathBuf> = None;
+    let mut checkpoint_every: u64 = 100;
+    let mut seed: Option<u64> = None;
+
+    let mut positional = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--resume" => resume = args.next().map(PathBuf::from),
+            "--checkpoint" => checkpoint_path = args.next().map(PathBuf::from),
+            "--checkpoint-every" => {
+                checkpoint_every = args.next().and_then(|s| s.parse().ok()).unwrap_or(100)
+            }
+            "--seed" => seed = args.next().and_then(|s| s.parse().ok()),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut args = positional.into_iter();
     let width: u32 = args.next().map(|s| s.parse().unwrap_or(100)).unwrap_or(100);
 
     let height: u32 = args.next().map(|s| s.parse().unwrap_or(100)).unwrap_or(100);
@@ -25,19 +35,45 @@ fn main() {
         .map(|s| s.parse().unwrap_or(false))
         .unwrap_or(false);
 
-    let field = Field::random(
-        usize::try_from(width).unwrap(),
-        usize::try_from(height).unwrap(),
-    );
+    let mut checkpoint = match &resume {
+        Some(path) => match Checkpoint::load(path) {
+            Ok(checkpoint) => checkpoint,
+            Err(why) => {
+                eprintln!("Failed to load checkpoint {}: {}", path.display(), why);
+                std::process::exit(1);
+            }
+        },
+        None => {
+            let seed = seed.unwrap_or_else(rand::random);
+            let field = match Field::random_seeded(
+                usize::try_from(width).unwrap(),
+                usize::try_from(height).unwrap(),
+                seed,
+            ) {
+                Ok(field) => field,
+                Err(why) => {
+                    eprintln!("{}", why);
+                    std::process::exit(1);
+                }
+            };
+            Checkpoint::new(Snapshot {
+                seed: Some(seed),
+                ..Snapshot::new(field)
+            })
+        }
+    };
+    // Keep checkpointing into the file a run was resumed from.
+    let checkpoint_path = checkpoint_path.or(resume);
+
+    let snapshot = &checkpoint.snapshot;
     if print {
-        println!("Round 0:\n{}", field);
+        println!("Round {}:\n{}", snapshot.generation, snapshot.field);
     }
 
-    let mut strategy = Strategy::new(field.clone());
+    let mut strategy =
+        Strategy::with_rule(snapshot.field.clone(), snapshot.rule, snapshot.topology);
 
-    let mut round = 1u32;
-    let mut visited = HashSet::new();
-    visited.insert(hash(&field));
+    let mut round = snapshot.generation + 1;
     let mut whole = Duration::new(0, 0);
     loop {
         sleep(Duration::from_millis(timeout));
@@ -51,21 +87,35 @@ fn main() {
         if field.is_none() {
             break;
         }
-        let field = field.unwrap();
-        if !visited.insert(hash(&field)) {
+        if !checkpoint.advance(field.unwrap()) {
             break;
         }
 
         if print {
             println!("Round {} ({:?})", round, elapsed);
-            println!("{}", field);
+            println!("{}", checkpoint.snapshot.field);
+        }
+
+        if let Some(path) = &checkpoint_path {
+            if checkpoint_every != 0 && checkpoint.snapshot.generation % checkpoint_every == 0 {
+                if let Err(why) = checkpoint.save(path) {
+                    eprintln!("Failed to write checkpoint {}: {}", path.display(), why);
+                }
+            }
         }
         round += 1;
     }
+    if let Some(path) = &checkpoint_path {
+        if let Err(why) = checkpoint.save(path) {
+            eprintln!("Failed to write checkpoint {}: {}", path.display(), why);
+        }
+    }
+    let field = &checkpoint.snapshot.field;
+    let cells = u64::try_from(field.width() * field.height()).unwrap();
     println!(
         "Finished after {}rnd and {:?} ({:?} pro Runde und Feld)",
         round + 1,
         whole,
-        whole / (round + 1 + (height * width))
+        whole.div_f64((round + 1 + cells) as f64)
     );
 }
@@ -1,33 +1,183 @@
-use crate::{Task, Update};
-use crossbeam_deque::Injector;
-use gol_lib::Field;
-use std::sync::{mpsc, Arc, RwLock};
+use crate::fault::Faults;
+use crate::{Region, StepError, Task, Update};
+use arc_swap::ArcSwap;
+use crossbeam_deque::{Injector, Steal};
+use gol_lib::{Activity, Chunk, ChunkedField, Field, GolError, Rule, Topology};
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{mpsc, Arc};
 use std::thread::{sleep, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-pub struct Worker {
+/// How often panicked workers are looked for while waiting on results.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Field which workers advance one part at a time.
+pub trait Board: Send + Sync + 'static {
+    type Part: Copy + Eq + Hash + Debug + Into<Region> + Send + 'static;
+    type Output: Send + 'static;
+
+    fn advance_part(
+        &self,
+        part: Self::Part,
+        rule: &Rule,
+        topology: Topology,
+    ) -> Result<Self::Output, GolError>;
+}
+
+/// Rows of a [`Field`] to advance by the given number of generations.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Band {
+    pub start: usize,
+    pub end: usize,
+    pub generations: usize,
+}
+
+impl Band {
+    /// First row and number of rows of the band extended by `halo` rows on both sides,
+    /// wrapping around on a torus and never exceeding the field.
+    pub fn with_halo(&self, halo: usize, height: usize, topology: Topology) -> (usize, usize) {
+        let count = self.end - self.start + 2 * halo;
+        if count >= height {
+            return (0, height);
+        }
+        match topology {
+            Topology::Bounded => {
+                let first = self.start.saturating_sub(halo);
+                (first, (self.end + halo).min(height) - first)
+            }
+            Topology::Torus => ((self.start + height - halo) % height, count),
+        }
+    }
+}
+
+impl From<Band> for Region {
+    fn from(band: Band) -> Self {
+        Region::Rows {
+            start: band.start,
+            end: band.end,
+        }
+    }
+}
+
+/// Dense fields are advanced in bands of rows.
+impl Board for Field {
+    type Part = Band;
+    /// Updates of every generation.
+    type Output = Vec<Vec<Update>>;
+
+    fn advance_part(
+        &self,
+        band: Band,
+        rule: &Rule,
+        topology: Topology,
+    ) -> Result<Self::Output, GolError> {
+        if band.generations == 1 {
+            let mut updates = Vec::new();
+            for row in band.start..band.end {
+                updates.extend(self.advance_row_with(row, rule, topology)?);
+            }
+            return Ok(vec![updates]);
+        }
+
+        // Cells beyond the edges of the halo are missing, which corrupts as many more rows of
+        // the halo each generation as the neighbourhood reaches but never the band itself.
+        let height = self.height();
+        let range = rule.neighbourhood().range();
+        let halo = band.generations * range;
+        let (first, count) = match band.with_halo(halo, height, topology) {
+            (_, count) if count < 3 => (0, height),
+            halo => halo,
+        };
+        let mut current = self.band(first, count)?;
+        // Skipping unchanged rows only depends on the copied rows, so it is exact even though
+        // they are advanced as a field of their own.
+        let mut activity = Activity::with_range(current.width(), count, topology, range);
+        let mut generations = Vec::with_capacity(band.generations);
+        for _ in 0..band.generations {
+            let mut next = current.clone();
+            let mut changed = Vec::new();
+            for row in (0..count).filter(|&row| activity.is_row_active(row)) {
+                changed.extend(current.advance_row_with(row, rule, topology)?);
+            }
+            let mut updates = Vec::new();
+            for &((x, y), cell) in &changed {
+                *next.value_mut((x, y)) = cell;
+                let y = (first + y) % height;
+                if (band.start..band.end).contains(&y) {
+                    updates.push(((x, y), cell));
+                }
+            }
+            activity.record(&changed);
+            generations.push(updates);
+            current = next;
+        }
+        Ok(generations)
+    }
+}
+
+impl From<(i64, i64)> for Region {
+    fn from(key: (i64, i64)) -> Self {
+        Region::Chunk { key }
+    }
+}
+
+/// Chunked fields are advanced chunk by chunk and are always unbounded.
+impl Board for ChunkedField {
+    type Part = (i64, i64);
+    type Output = ((i64, i64), Option<Chunk>);
+
+    fn advance_part(
+        &self,
+        key: (i64, i64),
+        rule: &Rule,
+        _: Topology,
+    ) -> Result<Self::Output, GolError> {
+        Ok((key, self.advance_chunk(key, rule)?))
+    }
+}
+
+/// Output of a worker, tagged with the task it answers.
+pub struct Reply<P, O> {
+    pub epoch: u64,
+    pub part: P,
+    pub output: Result<O, GolError>,
+}
+
+pub struct Worker<B: Board> {
     id: usize,
-    global_queue: Arc<Injector<Task>>,
-    field: Arc<RwLock<Field>>,
-    output: mpsc::SyncSender<Vec<Update>>,
+    global_queue: Arc<Injector<Task<B::Part>>>,
+    field: Arc<ArcSwap<B>>,
+    output: mpsc::SyncSender<Reply<B::Part, B::Output>>,
+    rule: Rule,
+    topology: Topology,
+    faults: Faults,
 }
 
-impl Worker {
+impl<B: Board> Worker<B> {
     pub fn new(
         id: usize,
-        injector: Arc<Injector<Task>>,
-        field: Arc<RwLock<Field>>,
-        output: mpsc::SyncSender<Vec<Update>>,
+        injector: Arc<Injector<Task<B::Part>>>,
+        field: Arc<ArcSwap<B>>,
+        output: mpsc::SyncSender<Reply<B::Part, B::Output>>,
+        rule: Rule,
+        topology: Topology,
+        faults: Faults,
     ) -> Self {
         Worker {
             id,
             global_queue: injector,
             field,
             output,
+            rule,
+            topology,
+            faults,
         }
     }
 
-    fn find_task(&self) -> Option<Task> {
+    fn find_task(&self) -> Option<Task<B::Part>> {
         std::iter::repeat_with(|| self.global_queue.steal())
             // Loop while no task was stolen and any steal operation needs to be retried.
             .find(|s| !s.is_retry())
@@ -38,19 +188,19 @@ impl Worker {
     pub fn start(&self) {
         loop {
             match self.find_task() {
-                Some(Task::Row(index)) => {
-                    let field = match self.field.read() {
-                        Err(why) => {
-                            eprintln!(
-                                "Worker #{} failed to get read lock on field: {:?}",
-                                self.id, why
-                            );
-                            break;
-                        }
-                        Ok(lock) => lock,
+                Some(Task::Advance { epoch, part }) => {
+                    self.faults.apply(self.id);
+                    // Generations are published as a whole, the worker keeps advancing the
+                    // one it loaded even if the next one is published meanwhile.
+                    let field = self.field.load();
+                    // Errors are sent as well so the coordinator doesn't wait for this part.
+                    let output = field.advance_part(part, &self.rule, self.topology);
+                    let reply = Reply {
+                        epoch,
+                        part,
+                        output,
                     };
-                    let updates = field.advance_row(index);
-                    if let Err(why) = self.output.send(updates) {
+                    if let Err(why) = self.output.send(reply) {
                         eprintln!("Worker #{} failed to send updates: {:?}", self.id, why);
                         break;
                     }
@@ -62,15 +212,22 @@ impl Worker {
     }
 }
 
+type Spawn = Box<dyn Fn(usize) -> JoinHandle<()> + Send>;
+
 pub struct Scheduler {
     handles: Vec<JoinHandle<()>>,
+    /// Starts the worker with the given id, used to replace panicked workers.
+    spawn: Spawn,
 }
 
 impl Scheduler {
-    pub fn new(
-        injector: Arc<Injector<Task>>,
-        field: Arc<RwLock<Field>>,
-        sender: mpsc::SyncSender<Vec<Update>>,
+    pub fn new<B: Board>(
+        injector: Arc<Injector<Task<B::Part>>>,
+        field: Arc<ArcSwap<B>>,
+        sender: mpsc::SyncSender<Reply<B::Part, B::Output>>,
+        rule: Rule,
+        topology: Topology,
+        faults: Faults,
     ) -> Self {
         let mut num_cpus = num_cpus::get();
 
@@ -78,53 +235,131 @@ impl Scheduler {
             num_cpus -= 1;
         }
 
-        Self::workers(injector, field, sender, num_cpus)
+        Self::workers(injector, field, sender, rule, topology, faults, num_cpus)
     }
 
     pub fn worker_count(&self) -> usize {
         self.handles.len()
     }
 
-    pub fn workers(
-        injector: Arc<Injector<Task>>,
-        field: Arc<RwLock<Field>>,
-        sender: mpsc::SyncSender<Vec<Update>>,
+    pub fn workers<B: Board>(
+        injector: Arc<Injector<Task<B::Part>>>,
+        field: Arc<ArcSwap<B>>,
+        sender: mpsc::SyncSender<Reply<B::Part, B::Output>>,
+        rule: Rule,
+        topology: Topology,
+        faults: Faults,
         count: usize,
     ) -> Self {
-        let mut workers_deques = Vec::with_capacity(count);
-        for _ in 0..count {
-            workers_deques.push(crossbeam_deque::Worker::<Task>::new_fifo());
-        }
-        let mut workers = Vec::with_capacity(count);
-        for id in 0..count {
-            let mut stealers = Vec::with_capacity(count - 1);
-            for worker_deque in &workers_deques {
-                stealers.push(worker_deque.stealer());
-            }
-            workers.push(Worker::new(
+        let spawn = move |id| {
+            let worker = Worker::new(
                 id,
                 Arc::clone(&injector),
                 Arc::clone(&field),
                 sender.clone(),
-            ));
+                rule,
+                topology,
+                faults.clone(),
+            );
+            std::thread::spawn(move || {
+                worker.start();
+            })
+        };
+
+        let handles = (0..count).map(&spawn).collect::<Vec<_>>();
+
+        Self {
+            handles,
+            spawn: Box::new(spawn),
         }
+    }
 
-        let handles = workers
-            .into_iter()
-            .map(|worker| {
-                std::thread::spawn(move || {
-                    worker.start();
-                })
-            })
-            .collect::<Vec<_>>();
+    /// Replaces workers which stopped and returns the ids of those which panicked.
+    pub fn respawn_finished(&mut self) -> Vec<usize> {
+        let mut panicked = Vec::new();
+        for (id, handle) in self.handles.iter_mut().enumerate() {
+            if handle.is_finished() {
+                let finished = std::mem::replace(handle, (self.spawn)(id));
+                if finished.join().is_err() {
+                    panicked.push(id);
+                }
+            }
+        }
+        panicked
+    }
 
-        Self { handles }
+    /// Queues the parts of one generation and waits for their outputs until the deadline
+    /// passes.
+    ///
+    /// Replies of earlier epochs are discarded, so every attempt at a generation has to
+    /// use a new epoch. On failure the parts no worker has picked up yet are taken off
+    /// the queue.
+    pub fn advance<P, O>(
+        &mut self,
+        injector: &Injector<Task<P>>,
+        receiver: &mpsc::Receiver<Reply<P, O>>,
+        epoch: u64,
+        parts: impl IntoIterator<Item = P>,
+        deadline: Duration,
+    ) -> Result<Vec<O>, StepError>
+    where
+        P: Copy + Eq + Hash + Debug + Into<Region>,
+    {
+        let deadline = Instant::now() + deadline;
+        let mut pending = HashSet::new();
+        for part in parts {
+            if pending.insert(part) {
+                injector.push(Task::Advance { epoch, part });
+            }
+        }
+        let expected = pending.len();
+
+        let mut outputs = Vec::with_capacity(expected);
+        let result = loop {
+            if pending.is_empty() {
+                break Ok(());
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::ZERO {
+                break Err(StepError::Timeout {
+                    received: expected - pending.len(),
+                    expected,
+                });
+            }
+            match receiver.recv_timeout(remaining.min(POLL_INTERVAL)) {
+                // Late reply to an attempt which already failed.
+                Ok(reply) if reply.epoch != epoch => {}
+                Ok(reply) if !pending.remove(&reply.part) => {
+                    break Err(StepError::Duplicate {
+                        part: reply.part.into(),
+                    })
+                }
+                Ok(Reply {
+                    output: Ok(output), ..
+                }) => outputs.push(output),
+                Ok(Reply {
+                    output: Err(why), ..
+                }) => break Err(StepError::Advance(why)),
+                // The scheduler keeps a sender for respawned workers, so the channel never
+                // disconnects.
+                Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => {
+                    if let Some(&worker) = self.respawn_finished().first() {
+                        break Err(StepError::WorkerPanicked { worker });
+                    }
+                }
+            }
+        };
+
+        if result.is_err() {
+            while !matches!(injector.steal(), Steal::Empty) {}
+        }
+        result.map(|()| outputs)
     }
 }
 
 impl Drop for Scheduler {
     fn drop(&mut self) {
-        for handle in self.handles.pop() {
+        while let Some(handle) = self.handles.pop() {
             if let Err(why) = handle.join() {
                 eprintln!("Failed to join worker thread: {:?}", why);
             }
@@ -0,0 +1,30 @@
+use gol_lib::{Field, Rule, Topology};
+
+fn matches_naive(rule: Rule, topology: Topology) {
+    for seed in 0..3 {
+        let field = Field::random_seeded(40, 30, seed).unwrap();
+        let naive = gol_naive::Strategy::with_rule(field.clone(), rule, topology);
+        let conc = gol_conc::Strategy::with_rule(field, rule, topology);
+
+        let mut generations = naive.zip(conc).take(60);
+        for (generation, (naive, conc)) in (1..).zip(&mut generations) {
+            assert_eq!(conc, naive, "Seed {} generation {}", seed, generation);
+        }
+    }
+}
+
+#[test]
+fn test_bounded_soups() {
+    matches_naive(Rule::CONWAY, Topology::Bounded);
+}
+
+#[test]
+fn test_torus_soups() {
+    matches_naive(Rule::CONWAY, Topology::Torus);
+}
+
+#[test]
+fn test_generations_soups() {
+    matches_naive(Rule::STAR_WARS, Topology::Bounded);
+    matches_naive(Rule::BRIANS_BRAIN, Topology::Torus);
+}
@@ -0,0 +1,63 @@
+use gol_lib::{Field, Rule, Topology};
+
+fn matches_naive(width: usize, height: usize, batch: usize, rule: Rule, topology: Topology) {
+    for seed in 0..2 {
+        let field = Field::random_seeded(width, height, seed).unwrap();
+        let naive = gol_naive::Strategy::with_rule(field.clone(), rule, topology);
+        let mut conc = gol_conc::Strategy::with_rule(field, rule, topology);
+        conc.set_batch(batch);
+
+        let mut generations = 0;
+        let mut conc = conc.fuse();
+        for (generation, naive) in (1..).zip(naive).take(40) {
+            assert_eq!(
+                conc.next().as_ref(),
+                Some(&naive),
+                "Batch {} seed {} generation {}",
+                batch,
+                seed,
+                generation
+            );
+            generations = generation;
+        }
+        if generations < 40 {
+            assert_eq!(conc.next(), None, "Batch {} seed {}", batch, seed);
+        }
+    }
+}
+
+#[test]
+fn test_bounded_batches() {
+    for batch in 1..=5 {
+        matches_naive(40, 60, batch, Rule::CONWAY, Topology::Bounded);
+    }
+}
+
+#[test]
+fn test_torus_batches() {
+    for batch in 1..=5 {
+        matches_naive(40, 60, batch, Rule::CONWAY, Topology::Torus);
+    }
+}
+
+#[test]
+fn test_batches_larger_than_field() {
+    matches_naive(6, 5, 8, Rule::CONWAY, Topology::Bounded);
+    matches_naive(6, 5, 8, Rule::CONWAY, Topology::Torus);
+}
+
+#[test]
+fn test_neighbourhood_batches() {
+    for rule in [
+        "R2,C0,S6-9,B7-8,NM",
+        "R3,C0,S2-5,B3-4,NN",
+        "B2/S34H",
+        "B2/S/C3V",
+    ] {
+        let rule = rule.parse().unwrap();
+        for batch in [1, 3] {
+            matches_naive(40, 60, batch, rule, Topology::Bounded);
+            matches_naive(40, 60, batch, rule, Topology::Torus);
+        }
+    }
+}
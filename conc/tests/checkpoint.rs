@@ -0,0 +1,76 @@
+use gol_conc::{Checkpoint, Strategy};
+use gol_lib::{Cell, Field, Snapshot};
+
+fn run(checkpoint: &mut Checkpoint, generations: u64) {
+    let snapshot = &checkpoint.snapshot;
+    let mut strategy =
+        Strategy::with_rule(snapshot.field.clone(), snapshot.rule, snapshot.topology);
+
+    for _ in 0..generations {
+        let advanced = match strategy.next() {
+            Some(field) => checkpoint.advance(field),
+            None => false,
+        };
+        if !advanced {
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_resume() {
+    let field = Field::random_seeded(40, 30, 7).unwrap();
+    let initial = Checkpoint::new(Snapshot {
+        seed: Some(7),
+        ..Snapshot::new(field)
+    });
+
+    let mut uninterrupted = initial.clone();
+    run(&mut uninterrupted, 30);
+
+    let path = std::env::temp_dir().join(format!("gol-checkpoint-{}.json", std::process::id()));
+    let mut interrupted = initial;
+    run(&mut interrupted, 12);
+    interrupted.save(&path).unwrap();
+
+    let mut resumed = Checkpoint::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(resumed, interrupted);
+
+    run(&mut resumed, 18);
+    assert_eq!(resumed, uninterrupted);
+}
+
+#[test]
+fn test_stable_hash() {
+    // Saved hashes have to keep matching after the toolchain changes.
+    assert_eq!(Checkpoint::hash(&Field::dead(3, 3)), 0x9e5d_2cdf_d450_8ddf);
+
+    let mut blinker = Field::dead(3, 3);
+    for y in 0..3 {
+        *blinker.value_mut((1, y)) = Cell::Alive;
+    }
+    assert_eq!(Checkpoint::hash(&blinker), 0x8c0d_e301_46ad_caf8);
+}
+
+/// Field whose alive cells spell out `number` in binary, so no two numbers share a field.
+fn numbered(number: usize) -> Field {
+    let mut field = Field::dead(5, 5);
+    for bit in (0..25).filter(|bit| number & 1 << bit != 0) {
+        *field.value_mut((bit % 5, bit / 5)) = Cell::Alive;
+    }
+    field
+}
+
+#[test]
+fn test_visited_is_capped() {
+    let mut checkpoint = Checkpoint::new(Snapshot::new(numbered(0)));
+    for number in 1..=Checkpoint::MAX_VISITED {
+        assert!(checkpoint.advance(numbered(number)));
+    }
+    assert!(checkpoint.visited.len() <= Checkpoint::MAX_VISITED);
+
+    // Short cycles are still detected.
+    assert!(checkpoint.advance(numbered(Checkpoint::MAX_VISITED + 1)));
+    assert!(!checkpoint.advance(numbered(Checkpoint::MAX_VISITED + 1)));
+}
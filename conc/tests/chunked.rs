@@ -0,0 +1,69 @@
+use gol_conc::ChunkedStrategy;
+use gol_lib::{Cell, ChunkedField, DyingState, Field, GolError, CHUNK_SIZE};
+use std::convert::TryFrom;
+
+#[test]
+fn test_matches_dense() {
+    // Place the soup across the borders of four chunks, far enough from the field's edges.
+    let soup = Field::random_seeded(30, 30, 11).unwrap();
+    let mut field = Field::dead(200, 200);
+    let offset = CHUNK_SIZE - 15;
+    for x in 0..soup.width() {
+        for y in 0..soup.height() {
+            *field.value_mut((x + offset, y + offset)) = *soup.value((x, y));
+        }
+    }
+
+    let mut dense = gol_naive::Strategy::new(field.clone());
+    let mut chunked = ChunkedStrategy::new(ChunkedField::try_from(&field).unwrap());
+    for generation in 1..=40 {
+        let (dense, chunked) = match (dense.next(), chunked.next()) {
+            (Some(dense), Some(chunked)) => (dense, chunked),
+            (None, None) => break,
+            (dense, chunked) => panic!(
+                "Generation {}: dense {:?} but chunked {:?}",
+                generation,
+                dense.is_some(),
+                chunked.is_some()
+            ),
+        };
+        assert_eq!(
+            chunked.to_field((0, 0), 200, 200).unwrap(),
+            dense,
+            "Generation {}",
+            generation
+        );
+    }
+}
+
+#[test]
+fn test_growth() {
+    // Glider moving towards negative coordinates.
+    let cells = [(1, 2), (0, 1), (2, 0), (1, 0), (0, 0)];
+    let mut glider = ChunkedField::new();
+    for &cords in &cells {
+        glider.set(cords, Cell::Alive).unwrap();
+    }
+
+    let moved = ChunkedStrategy::new(glider).nth(4 * 70 - 1).unwrap();
+    assert_eq!(moved.population(), cells.len());
+    for &(x, y) in &cells {
+        assert_eq!(moved.get((x - 70, y - 70)), Cell::Alive);
+    }
+    // Chunks left behind are freed.
+    assert!(moved.chunks().count() <= 4);
+}
+
+#[test]
+fn test_dying_cells_rejected() {
+    let mut field = Field::dead(4, 4);
+    *field.value_mut((1, 2)) = Cell::Dying(DyingState::FIRST);
+    assert_eq!(
+        ChunkedField::try_from(&field),
+        Err(GolError::InvalidState(2))
+    );
+    assert_eq!(
+        ChunkedField::new().set((1, 2), Cell::Dying(DyingState::FIRST)),
+        Err(GolError::InvalidState(2))
+    );
+}
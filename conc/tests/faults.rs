@@ -0,0 +1,89 @@
+use gol_conc::{Fault, Region, StepError, Strategy};
+use gol_lib::Field;
+use std::time::Duration;
+
+#[test]
+fn test_slow_workers_time_out() {
+    let field = Field::random_seeded(40, 30, 0).unwrap();
+    let mut strategy = Strategy::new(field);
+    strategy.set_deadline(Duration::from_millis(50));
+    for _ in 0..30 {
+        strategy.inject_fault(Fault::Delay(Duration::from_millis(200)));
+    }
+
+    match strategy.try_next() {
+        Err(StepError::Timeout { received, expected }) => {
+            assert!(received < expected);
+            assert_eq!(expected, 30);
+        }
+        other => panic!("Expected a timeout, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_panicked_worker_is_replaced() {
+    let field = Field::random_seeded(40, 30, 1).unwrap();
+    let mut naive = gol_naive::Strategy::new(field.clone());
+    let mut strategy = Strategy::new(field);
+    strategy.inject_fault(Fault::Panic);
+
+    match strategy.try_next() {
+        Err(StepError::WorkerPanicked { .. }) => {}
+        other => panic!("Expected a panicked worker, got {:?}", other.map(|_| ())),
+    }
+
+    // The failed generation is retried from the unchanged field.
+    for generation in 1..10 {
+        assert_eq!(
+            strategy.try_next(),
+            Ok(naive.next()),
+            "Generation {}",
+            generation
+        );
+    }
+}
+
+#[test]
+fn test_still_life_is_unchanged() {
+    let field = Field::dead(5, 5);
+    let mut strategy = Strategy::new(field);
+    assert_eq!(strategy.try_next(), Ok(None));
+}
+
+#[test]
+fn test_late_replies_are_discarded() {
+    let field = Field::random_seeded(40, 30, 2).unwrap();
+    let naive = gol_naive::Strategy::new(field.clone()).take(20);
+    let mut strategy = Strategy::new(field);
+    strategy.set_deadline(Duration::from_millis(20));
+    // Enough uneven delays that some attempts time out while replies are still in flight.
+    for part in 0..120u64 {
+        strategy.inject_fault(Fault::Delay(Duration::from_millis(part * 7 % 5 * 10)));
+    }
+
+    let mut timeouts = 0;
+    for (generation, expected) in (1..).zip(naive) {
+        let field = loop {
+            match strategy.try_next() {
+                Ok(Some(field)) => break field,
+                Ok(None) => panic!("Generation {} is unchanged", generation),
+                Err(StepError::Timeout { .. }) => timeouts += 1,
+                Err(why) => panic!("Generation {} failed: {}", generation, why),
+            }
+        };
+        assert_eq!(field, expected, "Generation {}", generation);
+    }
+    assert!(timeouts > 0, "No attempt timed out");
+}
+
+#[test]
+fn test_duplicate_names_the_part() {
+    let band = StepError::Duplicate {
+        part: Region::Rows { start: 8, end: 16 },
+    };
+    assert_eq!(band.to_string(), "band of rows 8 to 16 was advanced twice");
+    let chunk = StepError::Duplicate {
+        part: Region::Chunk { key: (-1, 2) },
+    };
+    assert_eq!(chunk.to_string(), "chunk (-1, 2) was advanced twice");
+}
@@ -1,5 +1,6 @@
 use gol_conc::Strategy;
 use gol_lib::{Field, ALIVE, DEAD};
+use std::convert::TryFrom;
 
 fn is_still(field: Field) {
     let mut strategy = Strategy::new(field);
@@ -14,55 +15,70 @@ fn is_still(field: Field) {
 
 #[test]
 fn test_block() {
-    is_still(Field::from([
-        [DEAD, DEAD, DEAD, DEAD],
-        [DEAD, ALIVE, ALIVE, DEAD],
-        [DEAD, ALIVE, ALIVE, DEAD],
-        [DEAD, DEAD, DEAD, DEAD],
-    ]));
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD],
+            [DEAD, ALIVE, ALIVE, DEAD],
+            [DEAD, ALIVE, ALIVE, DEAD],
+            [DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
 }
 
 #[test]
 fn test_beehive() {
-    is_still(Field::from([
-        [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
-        [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
-        [DEAD, ALIVE, DEAD, DEAD, ALIVE, DEAD],
-        [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
-        [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
-    ]));
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
 }
 
 #[test]
 fn test_loaf() {
-    is_still(Field::from([
-        [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
-        [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
-        [DEAD, ALIVE, DEAD, DEAD, ALIVE, DEAD],
-        [DEAD, DEAD, ALIVE, DEAD, ALIVE, DEAD],
-        [DEAD, DEAD, DEAD, ALIVE, DEAD, DEAD],
-        [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
-    ]));
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
 }
 
 #[test]
 fn test_boat() {
-    is_still(Field::from([
-        [DEAD, DEAD, DEAD, DEAD, DEAD],
-        [DEAD, ALIVE, ALIVE, DEAD, DEAD],
-        [DEAD, ALIVE, DEAD, ALIVE, DEAD],
-        [DEAD, DEAD, ALIVE, DEAD, DEAD],
-        [DEAD, DEAD, DEAD, DEAD, DEAD],
-    ]));
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
 }
 
 #[test]
 fn test_tub() {
-    is_still(Field::from([
-        [DEAD, DEAD, DEAD, DEAD, DEAD],
-        [DEAD, DEAD, ALIVE, DEAD, DEAD],
-        [DEAD, ALIVE, DEAD, ALIVE, DEAD],
-        [DEAD, DEAD, ALIVE, DEAD, DEAD],
-        [DEAD, DEAD, DEAD, DEAD, DEAD],
-    ]));
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
 }
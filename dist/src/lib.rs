@@ -0,0 +1,267 @@
+use crate::protocol::{digest, pack, receive, send, unpack, Request, Response};
+use gol_lib::{Field, Rule, Topology};
+use std::ffi::OsStr;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+mod protocol;
+mod worker;
+
+pub use worker::run_worker;
+
+/// Time workers have to connect to the coordinator after being spawned.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time workers have to exit after being told to shut down before they are killed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Advances a field split into horizontal strips, each owned by a separate worker process.
+///
+/// Workers exchange the outer rows of their strips directly with each other every generation,
+/// the coordinator only tells them when to step and collects the strips on demand. Driving
+/// the strategy through [`Strategy::step`] and [`Strategy::digest`] keeps the field off the
+/// coordinator, while iterating gathers every generation.
+pub struct Strategy {
+    width: usize,
+    height: usize,
+    /// Connections to the workers ordered from the top to the bottom strip.
+    connections: Vec<TcpStream>,
+    children: Vec<Child>,
+    digest: u64,
+}
+
+impl Strategy {
+    /// Spawns `workers` processes running `program worker <address>`, which has to call
+    /// [`run_worker`] with the address.
+    ///
+    /// Workers only exchange one row with each other, so rules whose neighbourhood reaches
+    /// further are rejected.
+    pub fn spawn(
+        program: impl AsRef<OsStr>,
+        field: Field,
+        workers: usize,
+        rule: Rule,
+        topology: Topology,
+    ) -> io::Result<Self> {
+        if rule.neighbourhood().range() > 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} reaches beyond the rows workers exchange", rule),
+            ));
+        }
+        let (width, height) = (field.width(), field.height());
+        let workers = workers.clamp(1, height);
+
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?.to_string();
+        let mut strategy = Strategy {
+            width,
+            height,
+            connections: Vec::with_capacity(workers),
+            children: Vec::with_capacity(workers),
+            digest: 0,
+        };
+        for _ in 0..workers {
+            let child = Command::new(&program).arg("worker").arg(&address).spawn()?;
+            strategy.children.push(child);
+        }
+
+        strategy.accept(&listener)?;
+        let mut listening = Vec::with_capacity(workers);
+        for connection in &strategy.connections {
+            match receive(connection)? {
+                Response::Listening(address) => listening.push(address),
+                response => return Err(unexpected(response)),
+            }
+        }
+
+        let mut field = field;
+        let mut rows = field.rows_mut().into_iter().map(|row| pack(row));
+        let mut digests = Vec::with_capacity(workers);
+        for (index, connection) in strategy.connections.iter().enumerate() {
+            let strip = (index + 1) * height / workers - index * height / workers;
+            let below = match (index + 1 < workers, topology) {
+                (true, _) => Some(listening[index + 1]),
+                (false, Topology::Torus) => Some(listening[0]),
+                (false, Topology::Bounded) => None,
+            };
+            let strip: Vec<_> = rows.by_ref().take(strip).collect();
+            digests.push(digest(strip.iter().map(Vec::as_slice)));
+            let init = Request::Init {
+                width,
+                rows: strip,
+                rule,
+                topology,
+                below,
+                above: index > 0 || topology == Topology::Torus,
+            };
+            send(connection, &init)?;
+        }
+        strategy.expect_all(|response| matches!(response, Response::Ready))?;
+        strategy.digest = combine(&digests);
+
+        Ok(strategy)
+    }
+
+    fn accept(&mut self, listener: &TcpListener) -> io::Result<()> {
+        listener.set_nonblocking(true)?;
+        let deadline = Instant::now() + CONNECT_TIMEOUT;
+        while self.connections.len() < self.children.len() {
+            match listener.accept() {
+                Ok((connection, _)) => {
+                    connection.set_nonblocking(false)?;
+                    connection.set_nodelay(true)?;
+                    self.connections.push(connection);
+                }
+                Err(why) if why.kind() == io::ErrorKind::WouldBlock => {
+                    for child in &mut self.children {
+                        if let Some(status) = child.try_wait()? {
+                            return Err(io::Error::other(format!(
+                                "worker exited before connecting: {}",
+                                status
+                            )));
+                        }
+                    }
+                    if Instant::now() > deadline {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "workers didn't connect in time",
+                        ));
+                    }
+                    sleep(Duration::from_millis(10));
+                }
+                Err(why) => return Err(why),
+            }
+        }
+        Ok(())
+    }
+
+    fn expect_all(&self, expected: impl Fn(&Response) -> bool) -> io::Result<Vec<Response>> {
+        let mut responses = Vec::with_capacity(self.connections.len());
+        for connection in &self.connections {
+            let response = receive(connection)?;
+            if !expected(&response) {
+                return Err(unexpected(response));
+            }
+            responses.push(response);
+        }
+        Ok(responses)
+    }
+
+    /// Advances every strip by one generation. Returns whether any cell changed.
+    pub fn step(&mut self) -> io::Result<bool> {
+        for connection in &self.connections {
+            send(connection, &Request::Step)?;
+        }
+        let responses = self.expect_all(|response| matches!(response, Response::Stepped { .. }))?;
+        let mut changed = false;
+        let mut digests = Vec::with_capacity(responses.len());
+        for response in responses {
+            if let Response::Stepped {
+                changed: strip_changed,
+                digest,
+            } = response
+            {
+                changed |= strip_changed;
+                digests.push(digest);
+            }
+        }
+        self.digest = combine(&digests);
+        Ok(changed)
+    }
+
+    /// Hash of the current field combined from the hashes the workers compute of their
+    /// strips, which detects cycles without gathering the field.
+    pub fn digest(&self) -> u64 {
+        self.digest
+    }
+
+    /// Collects the strips of all workers into one field.
+    pub fn gather(&mut self) -> io::Result<Field> {
+        for connection in &self.connections {
+            send(connection, &Request::Gather)?;
+        }
+        let mut field = Field::try_dead(self.width, self.height)
+            .map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why))?;
+        let mut rows = field.rows_mut().into_iter();
+        for response in self.expect_all(|response| matches!(response, Response::Strip(_)))? {
+            if let Response::Strip(strip) = response {
+                for packed in strip {
+                    let row = rows.next().ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "workers sent too many rows")
+                    })?;
+                    row.copy_from_slice(&unpack(&packed, self.width)?);
+                }
+            }
+        }
+        Ok(field)
+    }
+}
+
+/// Combines the digests of the strips from the top to the bottom one.
+fn combine(digests: &[u64]) -> u64 {
+    let bytes: Vec<[u8; 8]> = digests.iter().map(|digest| digest.to_le_bytes()).collect();
+    digest(bytes.iter().map(|bytes| &bytes[..]))
+}
+
+fn unexpected(response: Response) -> io::Error {
+    let message = match response {
+        Response::Failed(why) => format!("worker failed: {}", why),
+        response => format!("unexpected response {:?}", response),
+    };
+    io::Error::other(message)
+}
+
+/// Gathers the whole field onto the coordinator every generation, which only suits fields
+/// that fit into its memory.
+impl Iterator for Strategy {
+    type Item = Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.step().and_then(|changed| {
+            if changed {
+                self.gather().map(Some)
+            } else {
+                Ok(None)
+            }
+        }) {
+            Ok(field) => field,
+            Err(why) => {
+                eprintln!("Distributed step failed: {}", why);
+                None
+            }
+        }
+    }
+}
+
+impl Drop for Strategy {
+    fn drop(&mut self) {
+        for connection in &self.connections {
+            // Workers also exit once the connection closes.
+            let _ = send(connection, &Request::Shutdown);
+        }
+        self.connections.clear();
+        let deadline = Instant::now() + SHUTDOWN_TIMEOUT;
+        for child in &mut self.children {
+            // A stuck worker would block the coordinator forever.
+            let exited = loop {
+                match child.try_wait() {
+                    Ok(None) if Instant::now() < deadline => sleep(Duration::from_millis(10)),
+                    Ok(None) => break false,
+                    Ok(Some(_)) => break true,
+                    Err(why) => {
+                        eprintln!("Failed to wait for worker process: {:?}", why);
+                        break false;
+                    }
+                }
+            };
+            if !exited {
+                if let Err(why) = child.kill().and_then(|_| child.wait()) {
+                    eprintln!("Failed to kill worker process: {:?}", why);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use gol_dist::{run_worker, Strategy};
+use gol_lib::{Field, Rule, Topology};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+fn main() {
+    let mut args = std::env::args();
+    args.next();
+    let mut args = args.peekable();
+    if args.peek().map(String::as_str) == Some("worker") {
+        args.next();
+        let coordinator = args.next().and_then(|s| s.parse().ok());
+        let result = match coordinator {
+            Some(coordinator) => run_worker(coordinator),
+            None => {
+                eprintln!("Usage: gol-dist worker <coordinator address>");
+                std::process::exit(2);
+            }
+        };
+        if let Err(why) = result {
+            eprintln!("Worker failed: {}", why);
+            std::process::exit(1);
+        }
+        return;
+    }
+    let width: u32 = args.next().map(|s| s.parse().unwrap_or(100)).unwrap_or(100);
+
+    let height: u32 = args.next().map(|s| s.parse().unwrap_or(100)).unwrap_or(100);
+    let timeout: u64 = args.next().map(|s| s.parse().unwrap_or(250)).unwrap_or(250);
+    let print: bool = args
+        .next()
+        .map(|s| s.parse().unwrap_or(false))
+        .unwrap_or(false);
+    let workers: usize = args.next().map(|s| s.parse().unwrap_or(4)).unwrap_or(4);
+
+    let field = Field::random(
+        usize::try_from(width).unwrap(),
+        usize::try_from(height).unwrap(),
+    );
+    if print {
+        println!("Round 0:\n{}", field);
+    }
+
+    let program = std::env::current_exe().expect("Failed to locate the worker executable");
+    let mut strategy = match Strategy::spawn(
+        program,
+        field.clone(),
+        workers,
+        Rule::default(),
+        Topology::default(),
+    ) {
+        Ok(strategy) => strategy,
+        Err(why) => {
+            eprintln!("Failed to start workers: {}", why);
+            std::process::exit(1);
+        }
+    };
+
+    let mut round = 1u32;
+    // Only the digests of generations are compared, the field is gathered just for printing.
+    let mut visited = HashSet::new();
+    visited.insert(strategy.digest());
+    let mut whole = Duration::new(0, 0);
+    loop {
+        if timeout != 0 {
+            sleep(Duration::from_millis(timeout));
+        }
+
+        let now = Instant::now();
+        let changed = strategy.step();
+        let elapsed = now.elapsed();
+
+        whole += elapsed;
+
+        match changed {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(why) => {
+                eprintln!("Distributed step failed: {}", why);
+                break;
+            }
+        }
+        if !visited.insert(strategy.digest()) {
+            break;
+        }
+
+        if print {
+            println!("Round {} ({:?})", round, elapsed);
+            match strategy.gather() {
+                Ok(field) => println!("{}", field),
+                Err(why) => eprintln!("Failed to gather field: {}", why),
+            }
+        }
+        round += 1;
+    }
+    println!(
+        "Finished after {}rnd and {:?} ({:?} pro Runde und Feld)",
+        round + 1,
+        whole,
+        whole / (round + 1 + (height * width))
+    );
+}
@@ -0,0 +1,139 @@
+use fnv::FnvHasher;
+use gol_lib::{Cell, Rule, State, Topology};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::hash::Hasher;
+use std::io::{self, Read, Write};
+use std::net::SocketAddr;
+
+/// Largest message in bytes, far above the strips workers send but small enough that a
+/// corrupt length can't exhaust the memory of the receiver.
+pub const MAX_FRAME: u64 = 1 << 30;
+
+/// Messages from the coordinator to a worker.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request {
+    /// Hands a strip of rows to the worker.
+    Init {
+        width: usize,
+        rows: Vec<Vec<u8>>,
+        rule: Rule,
+        topology: Topology,
+        /// Listener of the worker owning the strip below, `None` at the bottom of a bounded field.
+        below: Option<SocketAddr>,
+        /// Whether a worker owns the strip above and will connect to this worker.
+        above: bool,
+    },
+    Step,
+    Gather,
+    Shutdown,
+}
+
+/// Messages from a worker to the coordinator.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    /// Address the worker accepts the connection from the worker above on.
+    Listening(SocketAddr),
+    Ready,
+    Stepped {
+        changed: bool,
+        /// [`digest`] of the rows of the strip after the step.
+        digest: u64,
+    },
+    Strip(Vec<Vec<u8>>),
+    Failed(String),
+}
+
+/// Writes a length prefixed message.
+pub fn send<T: Serialize>(mut writer: impl Write, message: &T) -> io::Result<()> {
+    let length = bincode::serialized_size(message).map_err(to_io)?;
+    check_length(length)?;
+    let mut frame = Vec::with_capacity(8 + length as usize);
+    frame.extend_from_slice(&length.to_le_bytes());
+    bincode::serialize_into(&mut frame, message).map_err(to_io)?;
+    writer.write_all(&frame)?;
+    writer.flush()
+}
+
+/// Reads a length prefixed message.
+pub fn receive<T: DeserializeOwned>(mut reader: impl Read) -> io::Result<T> {
+    let mut length = [0; 8];
+    reader.read_exact(&mut length)?;
+    let length = u64::from_le_bytes(length);
+    check_length(length)?;
+    let mut bytes = vec![0; length as usize];
+    reader.read_exact(&mut bytes)?;
+    bincode::deserialize(&bytes).map_err(to_io)
+}
+
+fn check_length(length: u64) -> io::Result<()> {
+    if length > MAX_FRAME {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "message of {} bytes exceeds the maximum of {}",
+                length, MAX_FRAME
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn to_io(error: bincode::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// Packs a row into a bitmap of its alive cells.
+///
+/// Rows with [`Cell::Dying`] cells are packed as the [`State`] of each cell instead, which is
+/// told apart by its length as fields are at least 3 cells wide.
+pub fn pack(row: &[Cell]) -> Vec<u8> {
+    if row.iter().any(|cell| matches!(cell, Cell::Dying(_))) {
+        return row.iter().map(|&cell| State::from(cell).0).collect();
+    }
+    let mut bitmap = vec![0u8; row.len().div_ceil(8)];
+    for (index, cell) in row.iter().enumerate() {
+        if cell.is_alive() {
+            bitmap[index / 8] |= 1 << (index % 8);
+        }
+    }
+    bitmap
+}
+
+pub fn unpack(bitmap: &[u8], width: usize) -> io::Result<Vec<Cell>> {
+    if bitmap.len() == width && width != width.div_ceil(8) {
+        return bitmap
+            .iter()
+            .map(|&state| {
+                Cell::try_from(State(state))
+                    .map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why))
+            })
+            .collect();
+    }
+    if bitmap.len() != width.div_ceil(8) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("row of {} bytes doesn't fit width {}", bitmap.len(), width),
+        ));
+    }
+    Ok((0..width)
+        .map(|index| {
+            if bitmap[index / 8] & 1 << (index % 8) != 0 {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            }
+        })
+        .collect())
+}
+
+/// Hashes packed rows with FNV-1a, so digests of strips can be compared between processes.
+pub fn digest<'a>(rows: impl IntoIterator<Item = &'a [u8]>) -> u64 {
+    let mut hasher = FnvHasher::default();
+    for row in rows {
+        hasher.write(&(row.len() as u64).to_le_bytes());
+        hasher.write(row);
+    }
+    hasher.finish()
+}
@@ -0,0 +1,148 @@
+use crate::protocol::{digest, pack, receive, send, unpack, Request, Response};
+use gol_lib::{Cell, Field, GolError, Rule, Topology};
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+struct Strip {
+    width: usize,
+    rows: Vec<Vec<Cell>>,
+    rule: Rule,
+    topology: Topology,
+    above: Option<TcpStream>,
+    below: Option<TcpStream>,
+}
+
+impl Strip {
+    fn packed(&self) -> Vec<Vec<u8>> {
+        self.rows.iter().map(|row| pack(row)).collect()
+    }
+
+    /// Exchanges the outer rows with the neighbouring strips and advances all rows.
+    fn step(&mut self) -> io::Result<bool> {
+        let top = pack(&self.rows[0]);
+        let bottom = pack(&self.rows[self.rows.len() - 1]);
+        let (above, below) = (self.above.as_ref(), self.below.as_ref());
+
+        let (upper, lower) = std::thread::scope(|scope| {
+            // Send in the background, neighbours are sending their rows at the same time.
+            let writer = scope.spawn(move || -> io::Result<()> {
+                if let Some(above) = above {
+                    send(above, &top)?;
+                }
+                if let Some(below) = below {
+                    send(below, &bottom)?;
+                }
+                Ok(())
+            });
+
+            let halo = |neighbour: Option<&TcpStream>| match neighbour {
+                Some(neighbour) => unpack(&receive::<Vec<u8>>(neighbour)?, self.width),
+                None => Ok(vec![Cell::Dead; self.width]),
+            };
+            let halos = (halo(above), halo(below));
+            writer
+                .join()
+                .unwrap_or_else(|_| Err(io::Error::other("halo writer panicked")))?;
+            Ok::<_, io::Error>((halos.0?, halos.1?))
+        })?;
+
+        // Halo rows above and below the strip take care of the vertical edges. Only the inner
+        // rows are advanced, so the field wrapping around vertically has no effect.
+        let mut padded = Field::try_dead(self.width, self.rows.len() + 2).map_err(invalid)?;
+        for (y, row) in padded.rows_mut().into_iter().enumerate() {
+            let source = match y {
+                0 => &upper,
+                y if y > self.rows.len() => &lower,
+                y => &self.rows[y - 1],
+            };
+            row.copy_from_slice(source);
+        }
+
+        let mut changed = false;
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            changed |= padded
+                .advance_row_into(index + 1, &self.rule, self.topology, row)
+                .map_err(invalid)?;
+        }
+        Ok(changed)
+    }
+}
+
+fn invalid(error: GolError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// Runs a worker process owning one strip of the field until the coordinator shuts it down.
+pub fn run_worker(coordinator: SocketAddr) -> io::Result<()> {
+    let connection = TcpStream::connect(coordinator)?;
+    connection.set_nodelay(true)?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    send(&connection, &Response::Listening(listener.local_addr()?))?;
+
+    let result = serve(&connection, &listener);
+    if let Err(why) = &result {
+        // The coordinator may be gone already, the error is returned either way.
+        let _ = send(&connection, &Response::Failed(why.to_string()));
+    }
+    result
+}
+
+fn serve(connection: &TcpStream, listener: &TcpListener) -> io::Result<()> {
+    let mut strip = match receive(connection)? {
+        Request::Init {
+            width,
+            rows,
+            rule,
+            topology,
+            below,
+            above,
+        } => {
+            let rows = rows
+                .iter()
+                .map(|row| unpack(row, width))
+                .collect::<io::Result<Vec<_>>>()?;
+            // Connect first, the listener of the worker below already accepts connections.
+            let below = below.map(TcpStream::connect).transpose()?;
+            let above = if above {
+                Some(listener.accept()?.0)
+            } else {
+                None
+            };
+            // Halo rows are small and sent every generation, so don't wait to batch them.
+            for neighbour in below.iter().chain(above.iter()) {
+                neighbour.set_nodelay(true)?;
+            }
+            Strip {
+                width,
+                rows,
+                rule,
+                topology,
+                above,
+                below,
+            }
+        }
+        request => return Err(unexpected(&request)),
+    };
+    send(connection, &Response::Ready)?;
+
+    loop {
+        match receive(connection)? {
+            Request::Step => {
+                let changed = strip.step()?;
+                let rows = strip.packed();
+                let digest = digest(rows.iter().map(Vec::as_slice));
+                send(connection, &Response::Stepped { changed, digest })?;
+            }
+            Request::Gather => send(connection, &Response::Strip(strip.packed()))?,
+            Request::Shutdown => return Ok(()),
+            request => return Err(unexpected(&request)),
+        }
+    }
+}
+
+fn unexpected(request: &Request) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unexpected request {:?}", request),
+    )
+}
@@ -0,0 +1,108 @@
+use gol_dist::Strategy;
+use gol_lib::{Field, Rule, Topology};
+
+const WORKER: &str = env!("CARGO_BIN_EXE_gol-dist");
+
+fn matches_naive(field: Field, workers: usize, rule: Rule, topology: Topology) {
+    let naive = gol_naive::Strategy::with_rule(field.clone(), rule, topology);
+    let mut dist = Strategy::spawn(WORKER, field, workers, rule, topology).unwrap();
+
+    for (generation, naive) in (1..).zip(naive.take(50)) {
+        assert_eq!(
+            dist.next(),
+            Some(naive),
+            "{} workers, generation {}",
+            workers,
+            generation
+        );
+    }
+}
+
+#[test]
+fn test_bounded() {
+    for workers in [1, 3, 7] {
+        matches_naive(
+            Field::random_seeded(30, 20, 5).unwrap(),
+            workers,
+            Rule::CONWAY,
+            Topology::Bounded,
+        );
+    }
+}
+
+#[test]
+fn test_torus() {
+    for workers in [1, 2, 4] {
+        matches_naive(
+            Field::random_seeded(25, 17, 9).unwrap(),
+            workers,
+            Rule::CONWAY,
+            Topology::Torus,
+        );
+    }
+}
+
+#[test]
+fn test_single_rows() {
+    // More workers than rows leaves every worker with a single row.
+    matches_naive(
+        Field::random_seeded(12, 5, 1).unwrap(),
+        8,
+        Rule::CONWAY,
+        Topology::Torus,
+    );
+}
+
+#[test]
+fn test_still_life() {
+    let mut field = Field::dead(6, 6);
+    for cords in [(2, 2), (3, 2), (2, 3), (3, 3)] {
+        *field.value_mut(cords) = gol_lib::Cell::Alive;
+    }
+    let mut dist = Strategy::spawn(WORKER, field, 3, Rule::CONWAY, Topology::Bounded).unwrap();
+    assert_eq!(dist.next(), None);
+}
+
+#[test]
+fn test_generations() {
+    matches_naive(
+        Field::random_seeded(25, 17, 3).unwrap(),
+        3,
+        Rule::BRIANS_BRAIN,
+        Topology::Torus,
+    );
+}
+
+#[test]
+fn test_digest_cycle() {
+    let mut field = Field::dead(5, 6);
+    for x in 1..4 {
+        *field.value_mut((x, 2)) = gol_lib::Cell::Alive;
+    }
+    let mut dist = Strategy::spawn(WORKER, field, 3, Rule::CONWAY, Topology::Bounded).unwrap();
+    let start = dist.digest();
+    assert!(dist.step().unwrap());
+    assert_ne!(dist.digest(), start);
+    assert!(dist.step().unwrap());
+    assert_eq!(dist.digest(), start);
+}
+
+#[test]
+fn test_oversized_frame() {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let worker = std::thread::spawn(move || gol_dist::run_worker(address));
+    let (mut connection, _) = listener.accept().unwrap();
+
+    // Skip the address the worker listens on.
+    let mut length = [0; 8];
+    connection.read_exact(&mut length).unwrap();
+    let mut listening = vec![0; u64::from_le_bytes(length) as usize];
+    connection.read_exact(&mut listening).unwrap();
+
+    connection.write_all(&u64::MAX.to_le_bytes()).unwrap();
+    let error = worker.join().unwrap().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
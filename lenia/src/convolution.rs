@@ -0,0 +1,102 @@
+use crate::Kernel;
+use gol_lib::{Grid, Topology};
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use std::sync::Arc;
+
+/// Convolves grids of a fixed size with a kernel by multiplying their spectra.
+///
+/// The discrete Fourier transform wraps around like a torus. Bounded grids are padded with
+/// as many dead cells as the kernel reaches, so nothing wraps onto them.
+pub struct Convolution {
+    width: usize,
+    /// Transformed kernel, scaled to undo the scaling of the inverse transform.
+    spectrum: Vec<Complex<f32>>,
+    rows: (Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>),
+    columns: (Arc<dyn Fft<f32>>, Arc<dyn Fft<f32>>),
+    buffer: Vec<Complex<f32>>,
+    column: Vec<Complex<f32>>,
+}
+
+impl Convolution {
+    pub fn new(kernel: &Kernel, width: usize, height: usize, topology: Topology) -> Self {
+        let (width, height) = match topology {
+            Topology::Bounded => (width + kernel.radius(), height + kernel.radius()),
+            Topology::Torus => (width, height),
+        };
+        let mut planner = FftPlanner::new();
+        let mut convolution = Convolution {
+            width,
+            spectrum: Vec::new(),
+            rows: (
+                planner.plan_fft_forward(width),
+                planner.plan_fft_inverse(width),
+            ),
+            columns: (
+                planner.plan_fft_forward(height),
+                planner.plan_fft_inverse(height),
+            ),
+            buffer: vec![Complex::default(); width * height],
+            column: vec![Complex::default(); height],
+        };
+
+        // The weight of an offset sits at its negation, so that each cell receives the
+        // weighted states around it. Offsets beyond the size wrap around the torus.
+        let scale = (width * height) as f32;
+        let mut spectrum = vec![Complex::default(); width * height];
+        for &((dx, dy), weight) in kernel.weights() {
+            let x = (-dx).rem_euclid(width as isize) as usize;
+            let y = (-dy).rem_euclid(height as isize) as usize;
+            spectrum[y * width + x].re += weight / scale;
+        }
+        convolution.transform(&mut spectrum, true);
+        convolution.spectrum = spectrum;
+        convolution
+    }
+
+    /// Transforms the rows and then the columns of the buffer.
+    fn transform(&mut self, buffer: &mut [Complex<f32>], forward: bool) {
+        let (rows, columns) = if forward {
+            (&self.rows.0, &self.columns.0)
+        } else {
+            (&self.rows.1, &self.columns.1)
+        };
+        rows.process(buffer);
+        for x in 0..self.width {
+            for (y, value) in self.column.iter_mut().enumerate() {
+                *value = buffer[y * self.width + x];
+            }
+            columns.process(&mut self.column);
+            for (y, value) in self.column.iter().enumerate() {
+                buffer[y * self.width + x] = *value;
+            }
+        }
+    }
+
+    /// Weighted sums of the states around every cell, in row-major order.
+    pub fn apply(&mut self, grid: &Grid<f32>) -> Vec<f32> {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer
+            .iter_mut()
+            .for_each(|value| *value = Complex::default());
+        for (y, row) in grid.rows().enumerate() {
+            for (x, &state) in row.iter().enumerate() {
+                buffer[y * self.width + x].re = state;
+            }
+        }
+
+        self.transform(&mut buffer, true);
+        for (value, weight) in buffer.iter_mut().zip(&self.spectrum) {
+            *value *= weight;
+        }
+        self.transform(&mut buffer, false);
+
+        let mut sums = Vec::with_capacity(grid.width() * grid.height());
+        for y in 0..grid.height() {
+            let row = &buffer[y * self.width..y * self.width + grid.width()];
+            sums.extend(row.iter().map(|value| value.re));
+        }
+        self.buffer = buffer;
+        sums
+    }
+}
@@ -0,0 +1,69 @@
+use gol_lib::GolError;
+
+/// Radially symmetric weights summing up to `1`, which average the states around a cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kernel {
+    radius: usize,
+    /// Offsets from the cell with their weights, leaving out those without weight.
+    weights: Vec<((isize, isize), f32)>,
+}
+
+impl Kernel {
+    /// Kernel whose weight at the distance `r` from the cell, relative to the radius, is
+    /// `profile(r)`. Only distances below `1` are part of the kernel.
+    pub fn radial(radius: usize, profile: impl Fn(f32) -> f32) -> Result<Self, GolError> {
+        let reach = radius as isize;
+        let mut weights = Vec::new();
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                let distance = ((dx * dx + dy * dy) as f32).sqrt() / radius as f32;
+                let weight = if distance < 1.0 {
+                    profile(distance)
+                } else {
+                    0.0
+                };
+                if weight > 0.0 {
+                    weights.push(((dx, dy), weight));
+                }
+            }
+        }
+
+        let total: f32 = weights.iter().map(|&(_, weight)| weight).sum();
+        if !total.is_normal() {
+            return Err(GolError::InvalidRule(format!(
+                "kernel of radius {} has no weight",
+                radius
+            )));
+        }
+        for (_, weight) in &mut weights {
+            *weight /= total;
+        }
+        Ok(Kernel { radius, weights })
+    }
+
+    /// Lenia's smooth ring, which peaks at half the radius.
+    pub fn lenia(radius: usize) -> Result<Self, GolError> {
+        Self::radial(radius, |r| {
+            if r > 0.0 {
+                (4.0 - 1.0 / (r * (1.0 - r))).exp()
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Sharp ring from `inner` to the radius as in SmoothLife, with `inner` relative to the
+    /// radius.
+    pub fn ring(radius: usize, inner: f32) -> Result<Self, GolError> {
+        Self::radial(radius, |r| if r >= inner { 1.0 } else { 0.0 })
+    }
+
+    pub fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Offsets from the cell with their weights, leaving out those without weight.
+    pub fn weights(&self) -> &[((isize, isize), f32)] {
+        &self.weights
+    }
+}
@@ -0,0 +1,106 @@
+use gol_lib::{Grid, Topology};
+
+mod convolution;
+mod kernel;
+
+use convolution::Convolution;
+pub use kernel::Kernel;
+
+/// Growth function mapping the weighted average around a cell to the change of its state,
+/// between `-1` and `1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Growth {
+    mu: f32,
+    sigma: f32,
+}
+
+impl Growth {
+    /// Gaussian bump around `mu` of width `sigma`, which is `1` at `mu` and tends to `-1`.
+    pub fn gaussian(mu: f32, sigma: f32) -> Self {
+        Growth { mu, sigma }
+    }
+
+    pub fn apply(&self, potential: f32) -> f32 {
+        let distance = (potential - self.mu) / self.sigma;
+        2.0 * (-distance * distance / 2.0).exp() - 1.0
+    }
+}
+
+/// Continuous Life-like automaton with states between `0` and `1`, of which SmoothLife and
+/// Lenia are variants.
+///
+/// Each generation the states around every cell are averaged by the [`Kernel`], and the cell
+/// changes by the [`Growth`] of that average times the time step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lenia {
+    kernel: Kernel,
+    growth: Growth,
+    time_step: f32,
+}
+
+impl Lenia {
+    pub fn new(kernel: Kernel, growth: Growth, time_step: f32) -> Self {
+        Lenia {
+            kernel,
+            growth,
+            time_step,
+        }
+    }
+
+    /// Parameters of the Orbium glider: a kernel of radius `13`, growth around `0.15` of
+    /// width `0.015` and ten steps per unit of time.
+    pub fn orbium() -> Self {
+        match Kernel::lenia(13) {
+            Ok(kernel) => Lenia::new(kernel, Growth::gaussian(0.15, 0.015), 0.1),
+            Err(why) => unreachable!("{}", why),
+        }
+    }
+
+    pub fn kernel(&self) -> &Kernel {
+        &self.kernel
+    }
+
+    /// Returns the next state of a cell with the given weighted average around it.
+    pub fn next(&self, state: f32, potential: f32) -> f32 {
+        (state + self.time_step * self.growth.apply(potential)).clamp(0.0, 1.0)
+    }
+}
+
+/// Advances a grid of continuous states, convolving it with the kernel through the fast
+/// Fourier transform so the cost per cell grows only logarithmically with the grid.
+pub struct Strategy {
+    field: Grid<f32>,
+    lenia: Lenia,
+    convolution: Convolution,
+}
+
+impl Strategy {
+    pub fn new(field: Grid<f32>, lenia: Lenia, topology: Topology) -> Self {
+        let convolution = Convolution::new(lenia.kernel(), field.width(), field.height(), topology);
+        Strategy {
+            field,
+            lenia,
+            convolution,
+        }
+    }
+}
+
+impl Iterator for Strategy {
+    type Item = Grid<f32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let potentials = self.convolution.apply(&self.field);
+        let mut field = self.field.clone();
+        for (state, potential) in field.cells_mut().iter_mut().zip(potentials) {
+            *state = self.lenia.next(*state, potential);
+        }
+
+        if field == self.field {
+            return None;
+        }
+
+        self.field = field.clone();
+
+        Some(field)
+    }
+}
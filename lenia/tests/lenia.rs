@@ -0,0 +1,135 @@
+use gol_lenia::{Growth, Kernel, Lenia, Strategy};
+use gol_lib::{Field, Grid, Topology};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Random states between `0` and `1` on the alive cells of a seeded soup.
+fn random(width: usize, height: usize, seed: u64) -> Grid<f32> {
+    let soup = Field::random_seeded(width, height, seed).unwrap();
+    let mut rng = StdRng::seed_from_u64(seed);
+    Grid::from(&soup).map(|cell| if cell.is_alive() { rng.gen() } else { 0.0 })
+}
+
+/// Next generation summing the weighted neighbours of every cell one by one.
+fn advance_directly(grid: &Grid<f32>, lenia: &Lenia, topology: Topology) -> Grid<f32> {
+    let (width, height) = (grid.width() as isize, grid.height() as isize);
+    let mut next = grid.clone();
+    for y in 0..height {
+        for x in 0..width {
+            let mut potential = 0.0;
+            for &((dx, dy), weight) in lenia.kernel().weights() {
+                let (column, row) = (x + dx, y + dy);
+                potential += weight
+                    * match topology {
+                        Topology::Torus => *grid.value((
+                            column.rem_euclid(width) as usize,
+                            row.rem_euclid(height) as usize,
+                        )),
+                        _ if (0..width).contains(&column) && (0..height).contains(&row) => {
+                            *grid.value((column as usize, row as usize))
+                        }
+                        _ => 0.0,
+                    };
+            }
+            let cords = (x as usize, y as usize);
+            *next.value_mut(cords) = lenia.next(*grid.value(cords), potential);
+        }
+    }
+    next
+}
+
+fn matches_direct(lenia: Lenia, width: usize, height: usize) {
+    for topology in [Topology::Bounded, Topology::Torus] {
+        let mut grid = random(width, height, 3);
+        let strategy = Strategy::new(grid.clone(), lenia.clone(), topology);
+        for (generation, next) in (1..).zip(strategy).take(5) {
+            let expected = advance_directly(&grid, &lenia, topology);
+            for (cell, expected) in next.cells().iter().zip(expected.cells()) {
+                assert!(
+                    (cell - expected).abs() < 1e-4,
+                    "{:?} generation {}: {} instead of {}",
+                    topology,
+                    generation,
+                    cell,
+                    expected
+                );
+            }
+            grid = next;
+        }
+    }
+}
+
+#[test]
+fn test_kernel() {
+    let kernel = Kernel::lenia(5).unwrap();
+    let total: f32 = kernel.weights().iter().map(|&(_, weight)| weight).sum();
+    assert!((total - 1.0).abs() < 1e-6);
+    let weight = |offset| {
+        kernel
+            .weights()
+            .iter()
+            .find(|&&(other, _)| other == offset)
+            .map(|&(_, weight)| weight)
+    };
+    // The ring peaks at half the radius and leaves out the cell itself.
+    assert_eq!(weight((0, 0)), None);
+    assert_eq!(weight((0, 5)), None);
+    assert!(weight((3, 0)) > weight((1, 0)));
+    assert_eq!(weight((2, -1)), weight((-1, 2)));
+
+    assert_eq!(Kernel::ring(3, 0.5).unwrap().weights().len(), 16);
+    assert!(Kernel::radial(0, |_| 1.0).is_err());
+    assert!(Kernel::ring(3, 1.0).is_err());
+}
+
+#[test]
+fn test_growth() {
+    let growth = Growth::gaussian(0.15, 0.015);
+    assert_eq!(growth.apply(0.15), 1.0);
+    assert!(growth.apply(0.0) < -0.99);
+    assert!(growth.apply(0.165).abs() < 0.25);
+}
+
+#[test]
+fn test_matches_direct_convolution() {
+    matches_direct(Lenia::orbium(), 40, 32);
+    let smooth = Lenia::new(
+        Kernel::ring(4, 0.3).unwrap(),
+        Growth::gaussian(0.3, 0.05),
+        0.5,
+    );
+    matches_direct(smooth, 17, 11);
+}
+
+/// Kernels larger than a torus wrap around it more than once.
+#[test]
+fn test_kernel_larger_than_field() {
+    matches_direct(Lenia::orbium(), 10, 8);
+}
+
+#[test]
+fn test_empty_field() {
+    let field = Grid::from(&Field::dead(20, 20)).map(|_| 0.0);
+    let mut strategy = Strategy::new(field, Lenia::orbium(), Topology::Torus);
+    assert_eq!(strategy.next(), None);
+}
+
+#[test]
+fn test_display() {
+    let grid = Grid::from_cells(3, 3, vec![0.0, 0.3, 0.5, 0.7, 1.0, 2.0, -1.0, 0.1, 0.9]).unwrap();
+    assert_eq!(
+        grid.to_string(),
+        "\u{25AF}\u{2591}\u{2592}\n\u{2593}\u{25AE}\u{25AE}\n\u{25AF}\u{25AF}\u{25AE}\n"
+    );
+}
+
+#[test]
+fn test_export() {
+    let strategy = Strategy::new(random(12, 10, 5), Lenia::orbium(), Topology::Torus);
+    let grid = strategy.take(3).last().unwrap();
+    let mut image = Vec::new();
+    grid.write_pgm(&mut image).unwrap();
+    let header = b"P5\n12 10\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(image.len(), header.len() + 12 * 10);
+}
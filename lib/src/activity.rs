@@ -0,0 +1,89 @@
+use crate::{Topology, Update};
+
+/// Tracks the cells changed by the last generation.
+///
+/// A cell can only change if itself or one of its neighbours changed in the last generation,
+/// all other cells lie in stable areas and can be skipped. Neighbours are all cells within the
+/// range of the neighbourhood along both axes.
+#[derive(Debug, Clone)]
+pub struct Activity {
+    width: usize,
+    height: usize,
+    topology: Topology,
+    range: usize,
+    /// `None` until the first generation is recorded, every cell is active before that.
+    changed: Option<Vec<bool>>,
+    changed_rows: Vec<bool>,
+}
+
+impl Activity {
+    pub fn new(width: usize, height: usize, topology: Topology) -> Self {
+        Self::with_range(width, height, topology, 1)
+    }
+
+    /// Tracks changes for a neighbourhood of the given [`range`](crate::Neighbourhood::range).
+    pub fn with_range(width: usize, height: usize, topology: Topology, range: usize) -> Self {
+        Activity {
+            width,
+            height,
+            topology,
+            range,
+            changed: None,
+            changed_rows: vec![true; height],
+        }
+    }
+
+    /// Replaces the changes of the previous generation with the given updates.
+    pub fn record(&mut self, updates: &[Update]) {
+        let cells = self.width * self.height;
+        let changed = self.changed.get_or_insert_with(|| vec![false; cells]);
+        changed.iter_mut().for_each(|cell| *cell = false);
+        self.changed_rows.iter_mut().for_each(|row| *row = false);
+
+        for &((x, y), _) in updates {
+            changed[y * self.width + x] = true;
+            self.changed_rows[y] = true;
+        }
+    }
+
+    /// Coordinates of the given one and its neighbours along one axis.
+    fn around(&self, value: usize, len: usize) -> impl Iterator<Item = usize> {
+        let wrap = self.topology == Topology::Torus;
+        // On a torus smaller than the neighbourhood every coordinate is a neighbour.
+        let range = if wrap {
+            self.range.min(len / 2)
+        } else {
+            self.range
+        };
+        let (first, last) = (value as isize - range as isize, value + range);
+        (first..=last as isize).filter_map(move |moved| {
+            if wrap {
+                Some(moved.rem_euclid(len as isize) as usize)
+            } else if (0..len as isize).contains(&moved) {
+                Some(moved as usize)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Whether the cell may change in the next generation.
+    pub fn is_active(&self, (x, y): (usize, usize)) -> bool {
+        let changed = match &self.changed {
+            Some(changed) => changed,
+            None => return true,
+        };
+        self.around(y, self.height).any(|row| {
+            self.changed_rows[row]
+                && self
+                    .around(x, self.width)
+                    .any(|column| changed[row * self.width + column])
+        })
+    }
+
+    /// Whether any cell of the row may change in the next generation.
+    pub fn is_row_active(&self, row: usize) -> bool {
+        self.around(row, self.height)
+            .any(|row| self.changed_rows[row])
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{GolError, ALIVE, DEAD, DYING};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// State of a single cell in a [`Field`](crate::Field).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum Cell {
+    #[default]
+    Dead,
+    Alive,
+    /// Refractory state of a Generations rule, counting up from state `2` after the cell
+    /// stopped being alive. Dying cells don't count as alive neighbours and can't be born.
+    Dying(DyingState),
+}
+
+/// State of a [`Cell::Dying`] cell, from [`DyingState::FIRST`] to [`DyingState::LAST`].
+///
+/// States `0` and `1` are dead and alive, so they can't be dying states.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DyingState(u8);
+
+impl DyingState {
+    /// State a cell passes to when it stops being alive.
+    pub const FIRST: DyingState = DyingState(2);
+    /// Last state before the cell is dead under a rule with the most states a [`u8`] counts.
+    pub const LAST: DyingState = DyingState(u8::MAX - 1);
+
+    pub fn new(state: u8) -> Result<Self, GolError> {
+        if (Self::FIRST.0..=Self::LAST.0).contains(&state) {
+            Ok(DyingState(state))
+        } else {
+            Err(GolError::InvalidState(state))
+        }
+    }
+
+    pub fn get(self) -> u8 {
+        self.0
+    }
+}
+
+impl Cell {
+    pub fn is_alive(self) -> bool {
+        self == Cell::Alive
+    }
+
+    /// Glyph used to render the cell.
+    ///
+    /// Dying cells fade out over the glyphs in [`DYING`], later states share the last one.
+    pub fn glyph(self) -> char {
+        match self {
+            Cell::Dead => DEAD,
+            Cell::Alive => ALIVE,
+            Cell::Dying(state) => {
+                DYING[usize::from(state.0 - DyingState::FIRST.0).min(DYING.len() - 1)]
+            }
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.glyph())
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        cell.glyph()
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = GolError;
+
+    fn try_from(glyph: char) -> Result<Self, Self::Error> {
+        match glyph {
+            DEAD => Ok(Cell::Dead),
+            ALIVE => Ok(Cell::Alive),
+            _ => match DYING.iter().position(|&dying| dying == glyph) {
+                Some(index) => Ok(Cell::Dying(DyingState(index as u8 + DyingState::FIRST.0))),
+                None => Err(GolError::InvalidGlyph(glyph)),
+            },
+        }
+    }
+}
+
+/// State of a cell under rules with more than two states.
+///
+/// `0` is dead and `1` is alive, higher states are [`Cell::Dying`] under Generations rules.
+/// Other automata like [`Wireworld`](crate::Wireworld) advance a [`Grid`](crate::Grid) of
+/// states with states of their own.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct State(pub u8);
+
+impl State {
+    pub const DEAD: State = State(0);
+    pub const ALIVE: State = State(1);
+
+    /// Glyph used to render the state.
+    ///
+    /// Unlike [`Cell::glyph`] every state up to `35` has a glyph of its own, the digits and
+    /// then the letters of base 36. Later states share `+`.
+    pub fn glyph(self) -> char {
+        match self {
+            State::DEAD => DEAD,
+            State::ALIVE => ALIVE,
+            State(state) => char::from_digit(u32::from(state), 36).unwrap_or('+'),
+        }
+    }
+}
+
+impl From<Cell> for State {
+    fn from(cell: Cell) -> Self {
+        match cell {
+            Cell::Dead => State::DEAD,
+            Cell::Alive => State::ALIVE,
+            Cell::Dying(state) => State(state.0),
+        }
+    }
+}
+
+impl TryFrom<State> for Cell {
+    type Error = GolError;
+
+    fn try_from(state: State) -> Result<Self, Self::Error> {
+        match state {
+            State::DEAD => Ok(Cell::Dead),
+            State::ALIVE => Ok(Cell::Alive),
+            State(other) => DyingState::new(other).map(Cell::Dying),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{Cell, Field, GolError, Rule};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+
+/// Width and height of a [`Chunk`].
+pub const CHUNK_SIZE: usize = 64;
+
+const SIZE: i64 = CHUNK_SIZE as i64;
+
+/// Dense square tile of a [`ChunkedField`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Chunk {
+    /// Cells in row-major order.
+    cells: Vec<Cell>,
+}
+
+impl Chunk {
+    fn dead() -> Self {
+        Chunk {
+            cells: vec![Cell::Dead; CHUNK_SIZE * CHUNK_SIZE],
+        }
+    }
+
+    /// Panics if the coordinates are outside of the chunk.
+    pub fn get(&self, (x, y): (usize, usize)) -> Cell {
+        self.cells[y * CHUNK_SIZE + x]
+    }
+
+    fn set(&mut self, (x, y): (usize, usize), cell: Cell) {
+        self.cells[y * CHUNK_SIZE + x] = cell;
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|cell| cell.is_alive()).count()
+    }
+}
+
+/// Unbounded field made of [`Chunk`]s, which only stores chunks with alive cells.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ChunkedField {
+    chunks: HashMap<(i64, i64), Chunk>,
+}
+
+impl ChunkedField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assembles a field from its chunks, dropping those without alive cells.
+    pub fn from_chunks(chunks: impl IntoIterator<Item = ((i64, i64), Chunk)>) -> Self {
+        ChunkedField {
+            chunks: chunks
+                .into_iter()
+                .filter(|(_, chunk)| chunk.cells.iter().any(|cell| cell.is_alive()))
+                .collect(),
+        }
+    }
+
+    /// Key of the chunk containing the cell and the cells position within it.
+    fn locate((x, y): (i64, i64)) -> ((i64, i64), (usize, usize)) {
+        (
+            (x.div_euclid(SIZE), y.div_euclid(SIZE)),
+            (x.rem_euclid(SIZE) as usize, y.rem_euclid(SIZE) as usize),
+        )
+    }
+
+    pub fn get(&self, cords: (i64, i64)) -> Cell {
+        let (key, local) = Self::locate(cords);
+        self.chunks
+            .get(&key)
+            .map_or(Cell::Dead, |chunk| chunk.get(local))
+    }
+
+    /// Only alive and dead cells are tracked, so [`Cell::Dying`] cells are rejected.
+    pub fn set(&mut self, cords: (i64, i64), cell: Cell) -> Result<(), GolError> {
+        let (key, local) = Self::locate(cords);
+        match cell {
+            Cell::Alive => self
+                .chunks
+                .entry(key)
+                .or_insert_with(Chunk::dead)
+                .set(local, cell),
+            Cell::Dead => {
+                if let Some(chunk) = self.chunks.get_mut(&key) {
+                    chunk.set(local, cell);
+                    if chunk.cells.iter().all(|cell| !cell.is_alive()) {
+                        self.chunks.remove(&key);
+                    }
+                }
+            }
+            Cell::Dying(state) => return Err(GolError::InvalidState(state.get())),
+        }
+        Ok(())
+    }
+
+    pub fn population(&self) -> usize {
+        self.chunks.values().map(Chunk::population).sum()
+    }
+
+    /// Keys of all allocated chunks in arbitrary order.
+    pub fn chunks(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.chunks.keys().copied()
+    }
+
+    /// Copies the given area starting at `origin` into a dense [`Field`].
+    pub fn to_field(
+        &self,
+        origin: (i64, i64),
+        width: usize,
+        height: usize,
+    ) -> Result<Field, GolError> {
+        let mut field = Field::try_dead(width, height)?;
+        for y in 0..height {
+            for x in 0..width {
+                let cords = (origin.0 + x as i64, origin.1 + y as i64);
+                *field.value_mut((x, y)) = self.get(cords);
+            }
+        }
+        Ok(field)
+    }
+
+    /// Chunks which may change in the next generation: all allocated chunks and the neighbours
+    /// they have alive cells next to.
+    pub fn active_chunks(&self) -> Vec<(i64, i64)> {
+        let last = CHUNK_SIZE - 1;
+        let mut active = HashSet::with_capacity(self.chunks.len());
+        for (&(chunk_x, chunk_y), chunk) in &self.chunks {
+            active.insert((chunk_x, chunk_y));
+
+            let row_alive = |y: usize| (0..CHUNK_SIZE).any(|x| chunk.get((x, y)).is_alive());
+            let column_alive = |x: usize| (0..CHUNK_SIZE).any(|y| chunk.get((x, y)).is_alive());
+            let edges = [
+                ((0, -1), row_alive(0)),
+                ((0, 1), row_alive(last)),
+                ((-1, 0), column_alive(0)),
+                ((1, 0), column_alive(last)),
+                ((-1, -1), chunk.get((0, 0)).is_alive()),
+                ((1, -1), chunk.get((last, 0)).is_alive()),
+                ((-1, 1), chunk.get((0, last)).is_alive()),
+                ((1, 1), chunk.get((last, last)).is_alive()),
+            ];
+            for ((dx, dy), alive) in edges {
+                if alive {
+                    active.insert((chunk_x + dx, chunk_y + dy));
+                }
+            }
+        }
+        active.into_iter().collect()
+    }
+
+    /// Returns the next generation of one chunk, `None` if all of its cells are dead. Fails
+    /// for rules which aren't supported on an unbounded field, see [`Rule::check_unbounded`].
+    pub fn advance_chunk(&self, key: (i64, i64), rule: &Rule) -> Result<Option<Chunk>, GolError> {
+        rule.check_unbounded()?;
+
+        // Copy the chunk with a border of the surrounding chunks to avoid lookups per cell.
+        const PADDED: usize = CHUNK_SIZE + 2;
+        let mut padded = vec![false; PADDED * PADDED];
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let neighbour = match self.chunks.get(&(key.0 + dx, key.1 + dy)) {
+                    Some(neighbour) => neighbour,
+                    None => continue,
+                };
+                for y in 0..PADDED {
+                    for x in 0..PADDED {
+                        let global_x = x as i64 - 1 - dx * SIZE;
+                        let global_y = y as i64 - 1 - dy * SIZE;
+                        if (0..SIZE).contains(&global_x) && (0..SIZE).contains(&global_y) {
+                            padded[y * PADDED + x] = neighbour
+                                .get((global_x as usize, global_y as usize))
+                                .is_alive();
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut next = Chunk::dead();
+        let mut any_alive = false;
+        for y in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                let mut alive = 0;
+                for row in y..y + 3 {
+                    for column in x..x + 3 {
+                        if (row, column) != (y + 1, x + 1) && padded[row * PADDED + column] {
+                            alive += 1;
+                        }
+                    }
+                }
+                let cell = if padded[(y + 1) * PADDED + x + 1] {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                };
+                if rule.next(cell, alive) == Cell::Alive {
+                    next.set((x, y), Cell::Alive);
+                    any_alive = true;
+                }
+            }
+        }
+        Ok(if any_alive { Some(next) } else { None })
+    }
+
+    /// Returns the next generation under the given rule, visiting only active chunks.
+    pub fn advance_with(&self, rule: &Rule) -> Result<ChunkedField, GolError> {
+        let mut chunks = HashMap::new();
+        for key in self.active_chunks() {
+            if let Some(chunk) = self.advance_chunk(key, rule)? {
+                chunks.insert(key, chunk);
+            }
+        }
+        Ok(ChunkedField { chunks })
+    }
+}
+
+/// Places the top left cell of the field at `(0, 0)`. Fails for fields with
+/// [`Cell::Dying`] cells, see [`ChunkedField::set`].
+impl TryFrom<&Field> for ChunkedField {
+    type Error = GolError;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        let mut chunked = ChunkedField::new();
+        for y in 0..field.height() {
+            for x in 0..field.width() {
+                match *field.value((x, y)) {
+                    Cell::Dead => {}
+                    cell => chunked.set((x as i64, y as i64), cell)?,
+                }
+            }
+        }
+        Ok(chunked)
+    }
+}
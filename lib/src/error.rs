@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the fallible constructors and accessors of [`Field`](crate::Field).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum GolError {
+    /// The requested dimensions are below the minimum of 3x3.
+    TooSmall { width: usize, height: usize },
+    /// The coordinates lie outside of the field.
+    OutOfBounds {
+        cords: (usize, usize),
+        width: usize,
+        height: usize,
+    },
+    /// A buffer of cells doesn't hold as many cells as it has to.
+    LengthMismatch { expected: usize, actual: usize },
+    /// The requested dimensions of a [`Volume`](crate::Volume) are below the minimum of 3x3x3.
+    VolumeTooSmall {
+        width: usize,
+        height: usize,
+        depth: usize,
+    },
+    /// The coordinates lie outside of the [`Volume`](crate::Volume).
+    OutOfVolume {
+        cords: (usize, usize, usize),
+        size: (usize, usize, usize),
+    },
+    /// The character is not the glyph of any [`Cell`](crate::Cell).
+    InvalidGlyph(char),
+    /// The state is no [`Cell`](crate::Cell), or a dying one where only dead and alive cells
+    /// are supported.
+    InvalidState(u8),
+    /// The rulestring could not be parsed.
+    InvalidRule(String),
+    /// The probability, as it was given, is not between `0` and `1`.
+    InvalidProbability(String),
+    /// The generation was dropped from or not yet reached by a [`History`](crate::History).
+    OutOfHistory {
+        generation: u64,
+        earliest: u64,
+        latest: u64,
+    },
+}
+
+impl fmt::Display for GolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GolError::TooSmall { width, height } => write!(
+                f,
+                "minimum size of a field is 3x3, got {}x{}",
+                width, height
+            ),
+            GolError::OutOfBounds {
+                cords: (x, y),
+                width,
+                height,
+            } => write!(
+                f,
+                "Out of field bounds: ({}, {}) on a {}x{} field",
+                x, y, width, height
+            ),
+            GolError::LengthMismatch { expected, actual } => {
+                write!(f, "expected {} cells, got {}", expected, actual)
+            }
+            GolError::VolumeTooSmall {
+                width,
+                height,
+                depth,
+            } => write!(
+                f,
+                "minimum size of a volume is 3x3x3, got {}x{}x{}",
+                width, height, depth
+            ),
+            GolError::OutOfVolume {
+                cords: (x, y, z),
+                size: (width, height, depth),
+            } => write!(
+                f,
+                "Out of volume bounds: ({}, {}, {}) in a {}x{}x{} volume",
+                x, y, z, width, height, depth
+            ),
+            GolError::InvalidGlyph(glyph) => write!(f, "{:?} is not a valid cell", glyph),
+            GolError::InvalidState(state) => write!(f, "state {} is not supported", state),
+            GolError::InvalidRule(rule) => write!(f, "invalid rule: {}", rule),
+            GolError::InvalidProbability(probability) => {
+                write!(f, "probability {} is not between 0 and 1", probability)
+            }
+            GolError::OutOfHistory {
+                generation,
+                earliest,
+                latest,
+            } => write!(
+                f,
+                "generation {} is not within the history of generations {} to {}",
+                generation, earliest, latest
+            ),
+        }
+    }
+}
+
+impl Error for GolError {}
@@ -0,0 +1,250 @@
+use crate::{
+    Cell, Field, GolError, Neighbourhood, State, Topology, Transition, ALIVE, DEAD, DYING,
+};
+use std::fmt;
+use std::io::{self, Write};
+
+/// Dense field of arbitrary states in row-major order, for automata whose states aren't
+/// [`Cell`]s, e.g. the continuous states of Lenia as `Grid<f32>`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Result<Self, GolError> {
+        if width < 3 || height < 3 {
+            return Err(GolError::TooSmall { width, height });
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order, which have to fill the grid exactly.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self, GolError> {
+        if width < 3 || height < 3 {
+            return Err(GolError::TooSmall { width, height });
+        }
+        if cells.len() != width * height {
+            return Err(GolError::LengthMismatch {
+                expected: width * height,
+                actual: cells.len(),
+            });
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn check_bounds(&self, (x, y): (usize, usize)) -> Result<(), GolError> {
+        if x >= self.width || y >= self.height {
+            return Err(GolError::OutOfBounds {
+                cords: (x, y),
+                width: self.width,
+                height: self.height,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, cords: (usize, usize)) -> Result<&T, GolError> {
+        self.check_bounds(cords)?;
+        Ok(self.value(cords))
+    }
+
+    pub fn get_mut(&mut self, cords: (usize, usize)) -> Result<&mut T, GolError> {
+        self.check_bounds(cords)?;
+        Ok(self.value_mut(cords))
+    }
+
+    /// Panics if the coordinates are out of bounds, see [`Grid::get`].
+    pub fn value(&self, (x, y): (usize, usize)) -> &T {
+        &self.cells[y * self.width + x]
+    }
+
+    /// Panics if the coordinates are out of bounds, see [`Grid::get_mut`].
+    pub fn value_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        &mut self.cells[y * self.width + x]
+    }
+
+    /// All cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Applies `f` to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Copy + Default + PartialEq> Grid<T> {
+    /// States of the given neighbourhood around a cell in reading order.
+    ///
+    /// Cells beyond the edges of a bounded grid are in the default state.
+    pub fn neighbours_in(
+        &self,
+        cords: (usize, usize),
+        neighbourhood: Neighbourhood,
+        topology: Topology,
+    ) -> Result<Vec<T>, GolError> {
+        self.check_bounds(cords)?;
+        let (width, height) = (self.width as isize, self.height as isize);
+        let (x, y) = (cords.0 as isize, cords.1 as isize);
+
+        let cells = neighbourhood.offsets().into_iter().map(|(dx, dy)| {
+            let (column, row) = (x + dx, y + dy);
+            match topology {
+                Topology::Torus => *self.value((
+                    column.rem_euclid(width) as usize,
+                    row.rem_euclid(height) as usize,
+                )),
+                Topology::Bounded if (0..width).contains(&column) && (0..height).contains(&row) => {
+                    *self.value((column as usize, row as usize))
+                }
+                Topology::Bounded => T::default(),
+            }
+        });
+        Ok(cells.collect())
+    }
+
+    /// Returns the resulting state of one cell under the given rule if it changes.
+    pub fn advance_one_with<R: Transition<T> + ?Sized>(
+        &self,
+        cords: (usize, usize),
+        rule: &R,
+        topology: Topology,
+    ) -> Result<Option<T>, GolError> {
+        let value = *self.get(cords)?;
+        let neighbours = self.neighbours_in(cords, rule.neighbourhood(), topology)?;
+        let next = rule.transition(value, &neighbours);
+        Ok(if next != value { Some(next) } else { None })
+    }
+
+    /// Returns the next generation under the given rule, `None` if no cell changes.
+    pub fn advance_with<R: Transition<T> + ?Sized>(
+        &self,
+        rule: &R,
+        topology: Topology,
+    ) -> Result<Option<Grid<T>>, GolError> {
+        let mut next = self.clone();
+        let mut changed = false;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if let Some(value) = self.advance_one_with((x, y), rule, topology)? {
+                    *next.value_mut((x, y)) = value;
+                    changed = true;
+                }
+            }
+        }
+        Ok(if changed { Some(next) } else { None })
+    }
+}
+
+impl From<&Field> for Grid<Cell> {
+    fn from(field: &Field) -> Self {
+        let mut cells = Vec::with_capacity(field.width() * field.height());
+        for y in 0..field.height() {
+            for x in 0..field.width() {
+                cells.push(*field.value((x, y)));
+            }
+        }
+        Grid {
+            width: field.width(),
+            height: field.height(),
+            cells,
+        }
+    }
+}
+
+impl Grid<State> {
+    /// Writes the grid as a binary greyscale PGM image with one pixel per cell.
+    ///
+    /// Alive cells are black and dead cells white. Dying states of a rule with `states`
+    /// states fade from dark to light grey as they get closer to dying.
+    pub fn write_pgm(&self, mut writer: impl Write, states: u8) -> io::Result<()> {
+        write!(writer, "P5\n{} {}\n255\n", self.width, self.height)?;
+        let last = u32::from(states.max(2) - 1);
+        let pixels: Vec<u8> = self
+            .cells
+            .iter()
+            .map(|&state| match state {
+                State::DEAD => u8::MAX,
+                State(state) => (255 * (u32::from(state) - 1) / last).min(254) as u8,
+            })
+            .collect();
+        writer.write_all(&pixels)
+    }
+}
+
+impl fmt::Display for Grid<State> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(
+                f,
+                "{}",
+                row.iter().map(|state| state.glyph()).collect::<String>()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Grid<f32> {
+    /// Writes the grid as a PGM image like [`Grid::<State>::write_pgm`], shading continuous
+    /// states from white at `0` to black at `1`.
+    pub fn write_pgm(&self, writer: impl Write) -> io::Result<()> {
+        // Alive is black and the highest dying state the lightest grey above dead.
+        let levels = self.map(|&state| match state.clamp(0.0, 1.0) {
+            state if state > 0.0 => State(1 + ((1.0 - state) * 253.0).round() as u8),
+            _ => State::DEAD,
+        });
+        levels.write_pgm(writer, u8::MAX)
+    }
+}
+
+/// Continuous states between `0` and `1` are shaded from [`DEAD`] over the [`DYING`] glyphs
+/// to [`ALIVE`].
+impl fmt::Display for Grid<f32> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shades = [DEAD, DYING[2], DYING[1], DYING[0], ALIVE];
+        for row in self.rows() {
+            let row = row.iter().map(|&state| {
+                let shade = (state.clamp(0.0, 1.0) * (shades.len() - 1) as f32).round();
+                shades[shade as usize]
+            });
+            writeln!(f, "{}", row.collect::<String>())?;
+        }
+        Ok(())
+    }
+}
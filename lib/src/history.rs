@@ -0,0 +1,206 @@
+use crate::{Cell, Field, GolError, Update};
+use std::collections::VecDeque;
+use std::mem::size_of;
+
+/// Generations between two fully stored fields.
+pub const DEFAULT_KEYFRAME_INTERVAL: usize = 64;
+/// Approximate memory the stored generations may use before the oldest ones are dropped.
+pub const DEFAULT_MEMORY_BUDGET: usize = 64 * 1024 * 1024;
+
+/// Generations from a keyframe up to the next keyframe.
+struct Segment {
+    start: u64,
+    keyframe: Field,
+    /// Changes from one generation to the next, starting at the keyframe.
+    deltas: Vec<Vec<Update>>,
+}
+
+impl Segment {
+    fn end(&self) -> u64 {
+        self.start + self.deltas.len() as u64
+    }
+}
+
+/// Records the generations produced by a strategy to move back and forth between them.
+pub struct History<S> {
+    strategy: S,
+    segments: VecDeque<Segment>,
+    keyframe_interval: usize,
+    memory_budget: usize,
+    memory: usize,
+    /// Set once the strategy stopped producing generations.
+    finished: bool,
+    generation: u64,
+    field: Field,
+}
+
+impl<S: Iterator<Item = Field>> History<S> {
+    /// Wraps a strategy which was created from the given field.
+    pub fn new(field: Field, strategy: S) -> Self {
+        Self::with_limits(
+            field,
+            strategy,
+            DEFAULT_KEYFRAME_INTERVAL,
+            DEFAULT_MEMORY_BUDGET,
+        )
+    }
+
+    pub fn with_limits(
+        field: Field,
+        strategy: S,
+        keyframe_interval: usize,
+        memory_budget: usize,
+    ) -> Self {
+        let mut history = History {
+            strategy,
+            segments: VecDeque::new(),
+            keyframe_interval: keyframe_interval.max(1),
+            memory_budget,
+            memory: 0,
+            finished: false,
+            generation: 0,
+            field: field.clone(),
+        };
+        history.push_keyframe(0, field);
+        history
+    }
+
+    /// Generation of the current field.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    /// Oldest generation which wasn't dropped to stay within the memory budget.
+    pub fn earliest(&self) -> u64 {
+        self.segments.front().map_or(0, |segment| segment.start)
+    }
+
+    /// Newest generation computed so far.
+    pub fn latest(&self) -> u64 {
+        self.segments.back().map_or(0, Segment::end)
+    }
+
+    /// Moves to the given generation, advancing the strategy if it wasn't computed yet.
+    pub fn seek(&mut self, generation: u64) -> Result<&Field, GolError> {
+        while generation > self.latest() {
+            if self.finished {
+                return Err(self.out_of_history(generation));
+            }
+            self.seek_recorded(self.latest())?;
+            match self.strategy.next() {
+                Some(next) => self.record(next),
+                None => self.finished = true,
+            }
+        }
+        self.seek_recorded(generation)?;
+        Ok(&self.field)
+    }
+
+    /// Moves one generation back.
+    pub fn step_back(&mut self) -> Result<&Field, GolError> {
+        match self.generation.checked_sub(1) {
+            Some(generation) => self.seek(generation),
+            None => Err(self.out_of_history(0)),
+        }
+    }
+
+    fn out_of_history(&self, generation: u64) -> GolError {
+        GolError::OutOfHistory {
+            generation,
+            earliest: self.earliest(),
+            latest: self.latest(),
+        }
+    }
+
+    fn seek_recorded(&mut self, generation: u64) -> Result<(), GolError> {
+        let index = self
+            .segments
+            .iter()
+            .position(|segment| segment.start <= generation && generation <= segment.end())
+            .ok_or_else(|| self.out_of_history(generation))?;
+        let segment = &self.segments[index];
+
+        // Replay from the keyframe unless the current field lies on the way.
+        let current_in_segment =
+            segment.start <= self.generation && self.generation <= segment.end();
+        if !current_in_segment || self.generation > generation {
+            self.field = segment.keyframe.clone();
+            self.generation = segment.start;
+        }
+        let from = (self.generation - segment.start) as usize;
+        let to = (generation - segment.start) as usize;
+        for delta in &segment.deltas[from..to] {
+            for &((x, y), cell) in delta {
+                self.field.inner[y][x] = cell;
+            }
+        }
+        self.generation = generation;
+        Ok(())
+    }
+
+    /// Appends the generation following the latest one, which has to be the current field.
+    fn record(&mut self, next: Field) {
+        let generation = self.latest() + 1;
+        let full = self
+            .segments
+            .back()
+            .is_none_or(|segment| segment.deltas.len() + 1 >= self.keyframe_interval);
+
+        if full {
+            self.push_keyframe(generation, next.clone());
+        } else {
+            let mut delta = Vec::new();
+            for (y, (row, next_row)) in self.field.inner.iter().zip(&next.inner).enumerate() {
+                for (x, (cell, next_cell)) in row.iter().zip(next_row).enumerate() {
+                    if cell != next_cell {
+                        delta.push(((x, y), *next_cell));
+                    }
+                }
+            }
+            self.memory += size_of::<Vec<Update>>() + delta.len() * size_of::<Update>();
+            if let Some(segment) = self.segments.back_mut() {
+                segment.deltas.push(delta);
+            }
+        }
+        self.field = next;
+        self.generation = generation;
+        self.enforce_budget();
+    }
+
+    fn push_keyframe(&mut self, start: u64, keyframe: Field) {
+        self.memory += Self::keyframe_size(&keyframe);
+        self.segments.push_back(Segment {
+            start,
+            keyframe,
+            deltas: Vec::new(),
+        });
+    }
+
+    fn keyframe_size(field: &Field) -> usize {
+        field.width() * field.height() * size_of::<Cell>()
+    }
+
+    /// Drops the oldest segments while over budget, always keeping the newest one.
+    fn enforce_budget(&mut self) {
+        while self.memory > self.memory_budget && self.segments.len() > 1 {
+            if let Some(segment) = self.segments.pop_front() {
+                self.memory -= Self::keyframe_size(&segment.keyframe);
+                for delta in &segment.deltas {
+                    self.memory -= size_of::<Vec<Update>>() + delta.len() * size_of::<Update>();
+                }
+            }
+        }
+    }
+}
+
+impl<S: Iterator<Item = Field>> Iterator for History<S> {
+    type Item = Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.seek(self.generation + 1).ok().cloned()
+    }
+}
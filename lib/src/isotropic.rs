@@ -0,0 +1,206 @@
+use crate::{Cell, GolError, Transition};
+use std::fmt;
+use std::str::FromStr;
+
+/// Letters of the configurations of zero to four alive neighbours in the order of
+/// [`REPRESENTATIVES`]. Five to eight alive neighbours reuse the letters of the complementary
+/// configurations of three to zero.
+const LETTERS: [&str; 5] = ["", "ce", "ceaikn", "ceaiknjqry", "ceaiknjqrytwz"];
+
+/// One neighbourhood of every configuration. Bits `0` to `8` are the 3x3 neighbourhood in
+/// reading order, the cell itself is bit `4`.
+const REPRESENTATIVES: [&[u16]; 5] = [
+    &[0],
+    &[1, 2],
+    &[5, 10, 3, 40, 33, 68],
+    &[69, 42, 11, 7, 98, 13, 14, 70, 41, 97],
+    &[325, 170, 15, 45, 99, 71, 106, 102, 43, 101, 105, 78, 108],
+];
+
+/// Order in which the letters of a rulestring are written.
+const CANONICAL: &str = "cekainyqjrtwz";
+
+/// Bit of the cell itself in a neighbourhood.
+const CENTRE: u16 = 1 << 4;
+/// All neighbours without the cell itself.
+const NEIGHBOURS: u16 = 0b1_1110_1111;
+
+/// Isotropic non-totalistic rule in Hensel notation, e.g. `B3/S2-i34q` for tlife.
+///
+/// Each number of alive neighbours may be followed by letters which select some of their
+/// configurations, or by a `-` and letters which exclude some. Configurations which are
+/// rotations or reflections of each other share a letter.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct IsotropicRule {
+    /// Whether the cell is alive next generation, by the bits of its neighbourhood.
+    table: [u64; 8],
+}
+
+impl IsotropicRule {
+    fn get(&self, neighbourhood: u16) -> bool {
+        let index = usize::from(neighbourhood);
+        self.table[index / 64] & 1 << (index % 64) != 0
+    }
+
+    fn set(&mut self, neighbourhood: u16) {
+        let index = usize::from(neighbourhood);
+        self.table[index / 64] |= 1 << (index % 64);
+    }
+
+    /// Letters of the configurations of the given number of alive neighbours.
+    fn letters(count: usize) -> &'static str {
+        LETTERS[count.min(8 - count)]
+    }
+
+    /// One neighbourhood of the configuration with the given letter, if it exists.
+    fn representative(count: usize, letter: Option<char>) -> Option<u16> {
+        let index = match letter {
+            Some(letter) => Self::letters(count).find(letter)?,
+            None if Self::letters(count).is_empty() => 0,
+            None => return None,
+        };
+        let representative = REPRESENTATIVES[count.min(8 - count)][index];
+        Some(if count > 4 {
+            representative ^ NEIGHBOURS
+        } else {
+            representative
+        })
+    }
+
+    /// Marks all rotations and reflections of the neighbourhood as alive next generation.
+    fn set_symmetric(&mut self, neighbourhood: u16) {
+        for rotations in 0..4 {
+            for mirror in [false, true] {
+                let mut transformed = 0;
+                for index in (0..9i32).filter(|index| neighbourhood & 1 << index != 0) {
+                    let (mut x, mut y) = (index % 3 - 1, index / 3 - 1);
+                    for _ in 0..rotations {
+                        let rotated = (-y, x);
+                        x = rotated.0;
+                        y = rotated.1;
+                    }
+                    if mirror {
+                        x = -x;
+                    }
+                    transformed |= 1 << ((y + 1) * 3 + x + 1);
+                }
+                self.set(transformed);
+            }
+        }
+    }
+}
+
+impl Transition for IsotropicRule {
+    fn transition(&self, cell: Cell, neighbours: &[Cell]) -> Cell {
+        let mut neighbourhood = if cell.is_alive() { CENTRE } else { 0 };
+        let bits = (0..9).filter(|&index| 1 << index != CENTRE);
+        for (neighbour, index) in neighbours.iter().zip(bits) {
+            if neighbour.is_alive() {
+                neighbourhood |= 1 << index;
+            }
+        }
+        if self.get(neighbourhood) {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        }
+    }
+}
+
+impl fmt::Display for IsotropicRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = |centre: u16| {
+            let mut part = String::new();
+            for count in 0..=8 {
+                let letters = Self::letters(count);
+                let included = |letter: char| {
+                    Self::representative(count, Some(letter))
+                        .is_some_and(|neighbourhood| self.get(neighbourhood | centre))
+                };
+                let (chosen, left): (String, String) = CANONICAL
+                    .chars()
+                    .filter(|&letter| letters.contains(letter))
+                    .partition(|&letter| included(letter));
+                let digit = char::from(b'0' + count as u8);
+                if letters.is_empty() {
+                    let neighbourhood = Self::representative(count, None).unwrap_or_default();
+                    if self.get(neighbourhood | centre) {
+                        part.push(digit);
+                    }
+                } else if left.is_empty() {
+                    part.push(digit);
+                } else if chosen.len() > left.len() {
+                    part.push(digit);
+                    part.push('-');
+                    part.push_str(&left);
+                } else if !chosen.is_empty() {
+                    part.push(digit);
+                    part.push_str(&chosen);
+                }
+            }
+            part
+        };
+        write!(f, "B{}/S{}", part(0), part(CENTRE))
+    }
+}
+
+impl FromStr for IsotropicRule {
+    type Err = GolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GolError::InvalidRule(s.to_string());
+        let mut rule = IsotropicRule { table: [0; 8] };
+        let mut parse = |part: &str, prefix: char, centre: u16| {
+            let mut chars = part
+                .strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))
+                .ok_or_else(invalid)?
+                .chars()
+                .peekable();
+            while let Some(digit) = chars.next() {
+                let count = digit
+                    .to_digit(10)
+                    .map(|count| count as usize)
+                    .filter(|&count| count <= 8)
+                    .ok_or_else(invalid)?;
+                let exclude = chars.next_if_eq(&'-').is_some();
+                let mut letters = String::new();
+                while let Some(letter) = chars.next_if(char::is_ascii_lowercase) {
+                    letters.push(letter);
+                }
+
+                let all = Self::letters(count);
+                if letters.chars().any(|letter| !all.contains(letter))
+                    || exclude && letters.is_empty()
+                {
+                    return Err(invalid());
+                }
+                let selected = if all.is_empty() {
+                    vec![None]
+                } else if letters.is_empty() || exclude {
+                    all.chars()
+                        .filter(|&letter| !letters.contains(letter))
+                        .map(Some)
+                        .collect()
+                } else {
+                    letters.chars().map(Some).collect()
+                };
+                for letter in selected {
+                    let neighbourhood = Self::representative(count, letter).ok_or_else(invalid)?;
+                    rule.set_symmetric(neighbourhood | centre);
+                }
+            }
+            Ok(())
+        };
+
+        let mut parts = s.trim().split('/');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(birth), Some(survival), None) => {
+                parse(birth, 'B', 0)?;
+                parse(survival, 'S', CENTRE)?;
+                Ok(rule)
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
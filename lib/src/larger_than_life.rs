@@ -0,0 +1,370 @@
+use crate::{Cell, DyingState, Field, GolError, Neighbourhood, Topology, Transition};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Larger than Life rule, which counts the alive cells of a Moore or von Neumann neighbourhood
+/// of any range and gives birth or survives within an interval of counts, e.g. Bosco's rule
+/// `R5,C0,M1,S34..58,B34..45,NM`.
+///
+/// `M1` includes the cell itself in its count, `C` is the number of states as for Generations
+/// rules with `C0` standing for two states, and `NM` and `NN` are the Moore and von Neumann
+/// neighbourhoods.
+///
+/// [`LargerThanLife::advance`] counts the neighbours of all cells at once with summed-area
+/// tables, so its cost doesn't depend on the range.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LargerThanLife {
+    neighbourhood: Neighbourhood,
+    /// Smallest and largest count giving birth to a dead cell.
+    birth: (usize, usize),
+    /// Smallest and largest count an alive cell survives with.
+    survival: (usize, usize),
+    /// Whether an alive cell counts itself.
+    middle: bool,
+    states: u8,
+}
+
+impl LargerThanLife {
+    /// Bosco's rule `R5,C0,M1,S34..58,B34..45,NM`.
+    pub const BOSCO: LargerThanLife = LargerThanLife {
+        neighbourhood: Neighbourhood::Moore(5),
+        birth: (34, 45),
+        survival: (34, 58),
+        middle: true,
+        states: 2,
+    };
+
+    /// Two state rule which doesn't count the cell itself, see [`LargerThanLife::with_middle`]
+    /// and [`LargerThanLife::with_states`].
+    pub fn new(
+        neighbourhood: Neighbourhood,
+        birth: RangeInclusive<usize>,
+        survival: RangeInclusive<usize>,
+    ) -> Result<Self, GolError> {
+        match neighbourhood {
+            Neighbourhood::Moore(range) | Neighbourhood::VonNeumann(range) if range > 0 => {}
+            _ => {
+                return Err(GolError::InvalidRule(format!(
+                    "{:?} isn't a Moore or von Neumann neighbourhood",
+                    neighbourhood
+                )))
+            }
+        }
+        Ok(LargerThanLife {
+            neighbourhood,
+            birth: (*birth.start(), *birth.end()),
+            survival: (*survival.start(), *survival.end()),
+            middle: false,
+            states: 2,
+        })
+    }
+
+    /// The same rule counting the cell itself or not.
+    pub fn with_middle(self, middle: bool) -> Self {
+        LargerThanLife { middle, ..self }
+    }
+
+    /// The same rule with the given number of states, of which `2` are dead and alive.
+    pub fn with_states(self, states: u8) -> Result<Self, GolError> {
+        if states < 2 {
+            return Err(GolError::InvalidRule(format!(
+                "{} states don't include dead and alive",
+                states
+            )));
+        }
+        Ok(LargerThanLife { states, ..self })
+    }
+
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    /// Returns the next state of a cell with the given count, which includes the cell itself
+    /// if the rule counts the middle.
+    pub fn next(&self, cell: Cell, count: usize) -> Cell {
+        let within = |(min, max): (usize, usize)| (min..=max).contains(&count);
+        match cell {
+            Cell::Dead if within(self.birth) => Cell::Alive,
+            Cell::Dead => Cell::Dead,
+            Cell::Alive if within(self.survival) => Cell::Alive,
+            Cell::Alive => self.decay(1),
+            Cell::Dying(state) => self.decay(state.get()),
+        }
+    }
+
+    /// State following the given one of a cell which isn't alive anymore.
+    pub(crate) fn decay(&self, state: u8) -> Cell {
+        match state.checked_add(1) {
+            Some(next) if next < self.states => {
+                DyingState::new(next).map_or(Cell::Dead, Cell::Dying)
+            }
+            _ => Cell::Dead,
+        }
+    }
+
+    /// Returns the next generation of the whole field.
+    pub fn advance(&self, field: &Field, topology: Topology) -> Field {
+        let counts = Counts::new(field, self.neighbourhood.range(), topology);
+        let mut next = field.clone();
+        for y in 0..field.height() {
+            let row = match self.neighbourhood {
+                Neighbourhood::VonNeumann(_) => counts.diamonds(y),
+                _ => counts.squares(y),
+            };
+            for (x, count) in row.into_iter().enumerate() {
+                let cell = *field.value((x, y));
+                let count = if !self.middle && cell.is_alive() {
+                    count - 1
+                } else {
+                    count
+                };
+                *next.value_mut((x, y)) = self.next(cell, count as usize);
+            }
+        }
+        next
+    }
+}
+
+/// Counts of alive cells around every cell of a field, from tables over the field padded by
+/// the range on every side.
+///
+/// Each table entry sums the cells above and left of it, or along the diagonal ending at it,
+/// so the sum over any rectangle or diagonal segment takes a constant number of lookups.
+struct Counts {
+    range: i64,
+    width: i64,
+    /// Width of the padded field.
+    padded_width: i64,
+    /// Cells of the padded field above and left of each entry, one row and column larger.
+    areas: Vec<u32>,
+    /// Cells on the diagonal up and left of each cell including it.
+    diagonals: Vec<u32>,
+    /// Cells on the diagonal up and right of each cell including it.
+    anti_diagonals: Vec<u32>,
+}
+
+impl Counts {
+    fn new(field: &Field, range: usize, topology: Topology) -> Self {
+        let (width, height) = (field.width() as i64, field.height() as i64);
+        let range = range as i64;
+        let (padded_width, padded_height) = (width + 2 * range, height + 2 * range);
+
+        let mut alive = Vec::with_capacity((padded_width * padded_height) as usize);
+        for y in -range..height + range {
+            for x in -range..width + range {
+                let cell = match topology {
+                    Topology::Torus => {
+                        field.value((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize))
+                    }
+                    _ if (0..width).contains(&x) && (0..height).contains(&y) => {
+                        field.value((x as usize, y as usize))
+                    }
+                    _ => &Cell::Dead,
+                };
+                alive.push(u32::from(cell.is_alive()));
+            }
+        }
+
+        // All tables have one more row and column than the padded field, so that entries
+        // before its first row and column are zero.
+        let stride = (padded_width + 1) as usize;
+        let len = stride * (padded_height + 1) as usize;
+        let (mut areas, mut diagonals, mut anti_diagonals) =
+            (vec![0; len], vec![0; len], vec![0; len]);
+        for y in 0..padded_height as usize {
+            for x in 0..padded_width as usize {
+                let cell = alive[y * padded_width as usize + x];
+                let entry = (y + 1) * stride + x + 1;
+                areas[entry] =
+                    cell + areas[entry - 1] + areas[entry - stride] - areas[entry - stride - 1];
+                diagonals[entry] = cell + diagonals[entry - stride - 1];
+                // Anti-diagonals continue to the right, so their extra column is the last one.
+                anti_diagonals[entry - 1] = cell + anti_diagonals[entry - stride];
+            }
+        }
+
+        Counts {
+            range,
+            width,
+            padded_width,
+            areas,
+            diagonals,
+            anti_diagonals,
+        }
+    }
+
+    fn stride(&self) -> i64 {
+        self.padded_width + 1
+    }
+
+    /// Cells within the rectangle between the given corners of the padded field, inclusive.
+    fn area(&self, (left, top): (i64, i64), (right, bottom): (i64, i64)) -> u32 {
+        let entry = |x: i64, y: i64| self.areas[(y * self.stride() + x) as usize];
+        entry(right + 1, bottom + 1) + entry(left, top)
+            - entry(left, bottom + 1)
+            - entry(right + 1, top)
+    }
+
+    /// Cells on the diagonal up and left of the given cell including it.
+    fn diagonal(&self, x: i64, y: i64) -> u32 {
+        if x < 0 || y < 0 {
+            return 0;
+        }
+        self.diagonals[((y + 1) * self.stride() + x + 1) as usize]
+    }
+
+    /// Cells on the diagonal up and right of the given cell including it.
+    fn anti_diagonal(&self, x: i64, y: i64) -> u32 {
+        if x >= self.padded_width || y < 0 {
+            return 0;
+        }
+        self.anti_diagonals[((y + 1) * self.stride() + x) as usize]
+    }
+
+    /// Counts of the Moore neighbourhoods of a row, including the cells themselves.
+    fn squares(&self, row: usize) -> Vec<u32> {
+        let (top, range) = (row as i64, self.range);
+        (0..self.width)
+            .map(|x| self.area((x, top), (x + 2 * range, top + 2 * range)))
+            .collect()
+    }
+
+    /// Counts of the von Neumann neighbourhoods of a row, including the cells themselves.
+    ///
+    /// The first diamond is summed row by row, each following one adds the cells along its
+    /// right edge and drops those along the left edge of the previous one.
+    fn diamonds(&self, row: usize) -> Vec<u32> {
+        let range = self.range;
+        let (first, y) = (range, row as i64 + range);
+        let mut count: u32 = (-range..=range)
+            .map(|dy| {
+                let reach = range - dy.abs();
+                self.area((first - reach, y + dy), (first + reach, y + dy))
+            })
+            .sum();
+
+        let mut counts = Vec::with_capacity(self.width as usize);
+        counts.push(count);
+        for x in first..first + self.width - 1 {
+            // The right edge of the diamond around `x + 1`, from its top to its right corner
+            // and on to just above its bottom corner.
+            let right = x + 1 + range;
+            let added = self.diagonal(right, y) - self.diagonal(x, y - range - 1)
+                + self.anti_diagonal(x + 1, y + range)
+                - self.anti_diagonal(right, y);
+            // The left edge of the diamond around `x`, from its left to its top corner and
+            // from just below its left corner to its bottom corner.
+            let left = x - range;
+            let removed = self.anti_diagonal(left, y) - self.anti_diagonal(x + 1, y - range - 1)
+                + self.diagonal(x, y + range)
+                - self.diagonal(left, y);
+            count = count + added - removed;
+            counts.push(count);
+        }
+        counts
+    }
+}
+
+/// Counts the neighbours one by one, which [`LargerThanLife::advance`] avoids.
+impl Transition for LargerThanLife {
+    fn transition(&self, cell: Cell, neighbours: &[Cell]) -> Cell {
+        let alive = neighbours.iter().filter(|cell| cell.is_alive()).count();
+        let middle = usize::from(self.middle && cell.is_alive());
+        self.next(cell, alive + middle)
+    }
+
+    fn neighbourhood(&self) -> Neighbourhood {
+        self.neighbourhood
+    }
+
+    fn death(&self) -> Cell {
+        self.decay(1)
+    }
+}
+
+impl fmt::Display for LargerThanLife {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let states = if self.states == 2 { 0 } else { self.states };
+        let neighbourhood = match self.neighbourhood {
+            Neighbourhood::VonNeumann(_) => 'N',
+            _ => 'M',
+        };
+        write!(
+            f,
+            "R{},C{},M{},S{}..{},B{}..{},N{}",
+            self.neighbourhood.range(),
+            states,
+            u8::from(self.middle),
+            self.survival.0,
+            self.survival.1,
+            self.birth.0,
+            self.birth.1,
+            neighbourhood
+        )
+    }
+}
+
+impl FromStr for LargerThanLife {
+    type Err = GolError;
+
+    /// Parses `R<range>,C<states>,M<0|1>,S<min>..<max>,B<min>..<max>,N<M|N>`, in which `C`,
+    /// `M` and `N` default to `C0`, `M0` and `NM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GolError::InvalidRule(s.to_string());
+        let number = |digits: &str| digits.parse::<usize>().map_err(|_| invalid());
+        let interval = |value: &str| match value.split_once("..") {
+            Some((min, max)) => Ok(number(min)?..=number(max)?),
+            None => Err(invalid()),
+        };
+
+        let (mut range, mut states, mut middle, mut von_neumann) = (None, 2, false, false);
+        let (mut survival, mut birth) = (None, None);
+        for item in s.trim().split(',') {
+            let mut chars = item.chars();
+            let letter = chars.next().ok_or_else(invalid)?.to_ascii_uppercase();
+            let value = chars.as_str();
+            match letter {
+                'R' => range = Some(number(value)?),
+                'C' => {
+                    states = match number(value)? {
+                        0 | 2 => 2,
+                        states if (3..=usize::from(u8::MAX)).contains(&states) => states as u8,
+                        _ => return Err(invalid()),
+                    }
+                }
+                'M' => {
+                    middle = match value {
+                        "0" => false,
+                        "1" => true,
+                        _ => return Err(invalid()),
+                    }
+                }
+                'S' => survival = Some(interval(value)?),
+                'B' => birth = Some(interval(value)?),
+                'N' => {
+                    von_neumann = match value {
+                        "M" | "m" => false,
+                        "N" | "n" => true,
+                        _ => return Err(invalid()),
+                    }
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        match (range, survival, birth) {
+            (Some(range), Some(survival), Some(birth)) => {
+                let neighbourhood = if von_neumann {
+                    Neighbourhood::VonNeumann(range)
+                } else {
+                    Neighbourhood::Moore(range)
+                };
+                LargerThanLife::new(neighbourhood, birth, survival)?
+                    .with_middle(middle)
+                    .with_states(states)
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
@@ -1,36 +1,121 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Write;
 
+mod activity;
+mod cell;
+mod chunked;
+mod error;
+mod grid;
+mod history;
+mod isotropic;
+mod larger_than_life;
+mod line;
+mod margolus;
+mod neighbourhood;
+mod rule;
+#[cfg(feature = "serde")]
+mod serialize;
+mod snapshot;
+mod sparse;
+mod topology;
+mod transition;
+mod update;
+mod volume;
+mod wireworld;
+
+pub use activity::Activity;
+pub use cell::{Cell, DyingState, State};
+pub use chunked::{Chunk, ChunkedField, CHUNK_SIZE};
+pub use error::GolError;
+pub use grid::Grid;
+pub use history::History;
+pub use isotropic::IsotropicRule;
+pub use larger_than_life::LargerThanLife;
+pub use line::{ElementaryRule, Line, LineRule, TotalisticRule};
+pub use margolus::BlockRule;
+pub use neighbourhood::{HexDisplay, Neighbourhood};
+pub use rule::Rule;
+pub use snapshot::Snapshot;
+pub use sparse::SparseField;
+pub use topology::Topology;
+pub use transition::Transition;
+pub use update::{Probability, UpdateMode};
+pub use volume::{Volume, VolumeRule};
+pub use wireworld::Wireworld;
+
+/// Glyph of an alive [`Cell`].
 pub const ALIVE: char = '\u{25AE}';
+/// Glyph of a dead [`Cell`].
 pub const DEAD: char = '\u{25AF}';
+/// Glyphs of [`Cell::Dying`] cells, from the first dying state on.
+pub const DYING: [char; 3] = ['\u{2593}', '\u{2592}', '\u{2591}'];
+
+/// New value of the cell at the given coordinates.
+pub type Update = ((usize, usize), Cell);
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Field {
-    inner: Vec<Vec<char>>,
+    inner: Vec<Vec<Cell>>,
 }
 
 impl Field {
+    /// Creates a field of the given size with randomly alive cells.
+    ///
+    /// Panics if the field is smaller than 3x3, see [`Field::try_random`].
     pub fn random(width: usize, height: usize) -> Self {
-        let mut field = Self::dead(width, height);
+        match Self::try_random(width, height) {
+            Ok(field) => field,
+            Err(why) => panic!("{}", why),
+        }
+    }
+
+    pub fn try_random(width: usize, height: usize) -> Result<Self, GolError> {
+        Self::random_from(width, height, &mut rand::thread_rng())
+    }
+
+    /// Creates a random field which is reproducible from the given seed.
+    pub fn random_seeded(width: usize, height: usize, seed: u64) -> Result<Self, GolError> {
+        Self::random_from(width, height, &mut StdRng::seed_from_u64(seed))
+    }
+
+    fn random_from(width: usize, height: usize, rng: &mut impl Rng) -> Result<Self, GolError> {
+        let mut field = Self::try_dead(width, height)?;
 
         for x in 0..width {
             for y in 0..height {
-                *field.value_mut((x, y)) = if rand::random::<bool>() { ALIVE } else { DEAD }
+                *field.value_mut((x, y)) = if rng.gen::<bool>() {
+                    Cell::Alive
+                } else {
+                    Cell::Dead
+                }
             }
         }
 
-        field
+        Ok(field)
     }
 
+    /// Creates a field of the given size with only dead cells.
+    ///
+    /// Panics if the field is smaller than 3x3, see [`Field::try_dead`].
     pub fn dead(width: usize, height: usize) -> Self {
+        match Self::try_dead(width, height) {
+            Ok(field) => field,
+            Err(why) => panic!("{}", why),
+        }
+    }
+
+    pub fn try_dead(width: usize, height: usize) -> Result<Self, GolError> {
         if width < 3 || height < 3 {
-            panic!("minimum size of a field is 3x3");
+            return Err(GolError::TooSmall { width, height });
         }
         let mut inner = Vec::with_capacity(height);
         for _ in 0..height {
-            inner.push(vec![DEAD; width]);
+            inner.push(vec![Cell::Dead; width]);
         }
-        Self { inner }
+        Ok(Self { inner })
     }
 
     pub fn height(&self) -> usize {
@@ -38,13 +123,24 @@ impl Field {
     }
 
     pub fn width(&self) -> usize {
+        // Every constructor guarantees at least 3 rows.
         self.inner[0].len()
     }
 
-    pub fn neighbours(&self, (x, y): (usize, usize)) -> Vec<char> {
+    fn check_bounds(&self, (x, y): (usize, usize)) -> Result<(), GolError> {
         if y >= self.height() || x >= self.width() {
-            panic!("Out of field bounds: ({}, {})", x, y);
+            return Err(GolError::OutOfBounds {
+                cords: (x, y),
+                width: self.width(),
+                height: self.height(),
+            });
         }
+        Ok(())
+    }
+
+    pub fn neighbours(&self, cords: (usize, usize)) -> Result<Vec<Cell>, GolError> {
+        self.check_bounds(cords)?;
+        let (x, y) = cords;
 
         let range_from = |x: usize, max: usize| match (x > 0, x < max - 1) {
             (true, true) => (x - 1)..(x + 2),
@@ -64,49 +160,201 @@ impl Field {
                 }
             }
         }
-        result
+        Ok(result)
+    }
+
+    /// The 8 cells around the given one in reading order, from the top left to the bottom right.
+    /// Cells beyond the edges of a bounded field are dead.
+    pub fn neighbourhood(
+        &self,
+        cords: (usize, usize),
+        topology: Topology,
+    ) -> Result<[Cell; 8], GolError> {
+        self.check_bounds(cords)?;
+        let (x, y) = cords;
+
+        // Adding `len - 1` modulo `len` steps back by one without underflowing.
+        let (width, height) = (self.width(), self.height());
+        let mut result = [Cell::Dead; 8];
+        let mut cells = result.iter_mut();
+        for row_offset in [height - 1, 0, 1] {
+            for column_offset in [width - 1, 0, 1] {
+                if column_offset == 0 && row_offset == 0 {
+                    continue;
+                }
+                let cell = cells.next().expect("8 neighbours");
+                let (column, row) = ((x + column_offset) % width, (y + row_offset) % height);
+                let wrapped = (column_offset == width - 1 && x == 0)
+                    || (column_offset == 1 && column == 0)
+                    || (row_offset == height - 1 && y == 0)
+                    || (row_offset == 1 && row == 0);
+                if topology == Topology::Torus || !wrapped {
+                    *cell = self.inner[row][column];
+                }
+            }
+        }
+        Ok(result)
     }
 
-    pub fn value(&self, (x, y): (usize, usize)) -> &char {
+    /// Cells of the given neighbourhood around a cell in reading order.
+    ///
+    /// Cells beyond the edges of a bounded field are dead. On a torus smaller than the
+    /// neighbourhood, cells can be counted more than once.
+    pub fn neighbours_in(
+        &self,
+        cords: (usize, usize),
+        neighbourhood: Neighbourhood,
+        topology: Topology,
+    ) -> Result<Vec<Cell>, GolError> {
+        self.check_bounds(cords)?;
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        let (x, y) = (cords.0 as isize, cords.1 as isize);
+
+        let cells = neighbourhood.offsets().into_iter().map(|(dx, dy)| {
+            let (column, row) = (x + dx, y + dy);
+            match topology {
+                Topology::Torus => {
+                    self.inner[row.rem_euclid(height) as usize][column.rem_euclid(width) as usize]
+                }
+                Topology::Bounded if (0..width).contains(&column) && (0..height).contains(&row) => {
+                    self.inner[row as usize][column as usize]
+                }
+                Topology::Bounded => Cell::Dead,
+            }
+        });
+        Ok(cells.collect())
+    }
+
+    /// Renders the field as a hexagonal grid, for rules with a
+    /// [`Neighbourhood::Hexagonal`].
+    pub fn display_hex(&self) -> HexDisplay<'_> {
+        HexDisplay { field: self }
+    }
+
+    /// Panics if the coordinates are out of bounds, see [`Field::get`].
+    pub fn value(&self, (x, y): (usize, usize)) -> &Cell {
         &self.inner[y][x]
     }
 
-    pub fn value_mut(&mut self, (x, y): (usize, usize)) -> &mut char {
+    /// Panics if the coordinates are out of bounds, see [`Field::get_mut`].
+    pub fn value_mut(&mut self, (x, y): (usize, usize)) -> &mut Cell {
         &mut self.inner[y][x]
     }
 
+    /// Mutable rows of the field, which can be written independently of each other.
+    pub fn rows_mut(&mut self) -> Vec<&mut [Cell]> {
+        self.inner.iter_mut().map(Vec::as_mut_slice).collect()
+    }
+
+    /// Copies `count` rows starting at `first`, continuing with the top row past the bottom one.
+    pub fn band(&self, first: usize, count: usize) -> Result<Field, GolError> {
+        if count < 3 {
+            return Err(GolError::TooSmall {
+                width: self.width(),
+                height: count,
+            });
+        }
+        self.check_bounds((0, first))?;
+        self.check_bounds((0, count - 1))?;
+
+        let inner = (first..first + count)
+            .map(|row| self.inner[row % self.height()].clone())
+            .collect();
+        Ok(Self { inner })
+    }
+
+    pub fn get(&self, cords: (usize, usize)) -> Result<&Cell, GolError> {
+        self.check_bounds(cords)?;
+        Ok(self.value(cords))
+    }
+
+    pub fn get_mut(&mut self, cords: (usize, usize)) -> Result<&mut Cell, GolError> {
+        self.check_bounds(cords)?;
+        Ok(self.value_mut(cords))
+    }
+
+    /// Writes the field as a PGM image, see [`Grid::write_pgm`].
+    pub fn write_pgm(&self, writer: impl std::io::Write, states: u8) -> std::io::Result<()> {
+        Grid::from(self)
+            .map(|&cell| State::from(cell))
+            .write_pgm(writer, states)
+    }
+
     /// Returns the resulting value of one cell if it changes.
-    pub fn advance_one(&self, cords: (usize, usize)) -> Option<char> {
-        let neighbours = self.neighbours(cords);
-        let value = self.value(cords);
-
-        let alive = neighbours.iter().filter(|char| char == &&ALIVE).count();
-
-        // Breakdown of the rules
-        /*
-        1. Any live cell with fewer than two live neighbours dies, as if by underpopulation.
-        2. Any live cell with two or three live neighbours lives on to the next generation.
-        3. Any live cell with more than three live neighbours dies, as if by overpopulation.
-        4.Any dead cell with exactly three live neighbours becomes a live cell, as if by reproduction.
-         */
-        match (value, alive < 2, alive == 2, alive == 3, alive > 3) {
-            (&ALIVE, true, _, _, _) => Some(DEAD), // underpopulation
-            (&ALIVE, _, true, _, _) => None,       // next generation
-            (&ALIVE, _, _, true, _) => None,       // next generation
-            (&ALIVE, _, _, _, true) => Some(DEAD), // overpopulation
-            (&DEAD, _, _, true, _) => Some(ALIVE), // reproduction
-            _ => None,
-        }
-    }
-
-    pub fn advance_row(&self, row: usize) -> Vec<((usize, usize), char)> {
+    pub fn advance_one(&self, cords: (usize, usize)) -> Result<Option<Cell>, GolError> {
+        self.advance_one_with(cords, &Rule::default(), Topology::default())
+    }
+
+    /// Returns the resulting value of one cell under the given rule if it changes.
+    pub fn advance_one_with<T: Transition + ?Sized>(
+        &self,
+        cords: (usize, usize),
+        rule: &T,
+        topology: Topology,
+    ) -> Result<Option<Cell>, GolError> {
+        let value = *self.get(cords)?;
+        let next = match rule.neighbourhood() {
+            // The 8 surrounding cells don't need to be allocated.
+            Neighbourhood::Moore(1) => {
+                rule.transition(value, &self.neighbourhood(cords, topology)?)
+            }
+            neighbourhood => {
+                rule.transition(value, &self.neighbours_in(cords, neighbourhood, topology)?)
+            }
+        };
+
+        Ok(if next != value { Some(next) } else { None })
+    }
+
+    pub fn advance_row(&self, row: usize) -> Result<Vec<Update>, GolError> {
+        self.advance_row_with(row, &Rule::default(), Topology::default())
+    }
+
+    pub fn advance_row_with<T: Transition + ?Sized>(
+        &self,
+        row: usize,
+        rule: &T,
+        topology: Topology,
+    ) -> Result<Vec<Update>, GolError> {
+        self.check_bounds((0, row))?;
+
         let mut updates = Vec::new();
         for column in 0..self.width() {
-            if let Some(update) = self.advance_one((column, row)) {
+            if let Some(update) = self.advance_one_with((column, row), rule, topology)? {
                 updates.push(((column, row), update));
             }
         }
-        updates
+        Ok(updates)
+    }
+
+    /// Writes the next generation of a row into `output`, which has to be as wide as the field.
+    /// Returns whether any cell changed.
+    pub fn advance_row_into<T: Transition + ?Sized>(
+        &self,
+        row: usize,
+        rule: &T,
+        topology: Topology,
+        output: &mut [Cell],
+    ) -> Result<bool, GolError> {
+        self.check_bounds((0, row))?;
+        if output.len() != self.width() {
+            return Err(GolError::LengthMismatch {
+                expected: self.width(),
+                actual: output.len(),
+            });
+        }
+
+        let mut changed = false;
+        for (column, cell) in output.iter_mut().enumerate() {
+            *cell = match self.advance_one_with((column, row), rule, topology)? {
+                Some(next) => {
+                    changed = true;
+                    next
+                }
+                None => self.inner[row][column],
+            };
+        }
+        Ok(changed)
     }
 }
 
@@ -120,18 +368,45 @@ impl fmt::Display for Field {
         write!(&mut frame, "]")?;
         writeln!(f, "{}", frame)?;
         for row in &self.inner {
-            writeln!(f, "{}", row.iter().collect::<String>())?;
+            writeln!(
+                f,
+                "{}",
+                row.iter().map(|cell| cell.glyph()).collect::<String>()
+            )?;
         }
         write!(f, "{}", frame)
     }
 }
 
-impl<const WIDTH: usize, const HEIGHT: usize> From<[[char; WIDTH]; HEIGHT]> for Field {
-    fn from(array: [[char; WIDTH]; HEIGHT]) -> Self {
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<[[Cell; WIDTH]; HEIGHT]> for Field {
+    type Error = GolError;
+
+    fn try_from(array: [[Cell; WIDTH]; HEIGHT]) -> Result<Self, Self::Error> {
+        if WIDTH < 3 || HEIGHT < 3 {
+            return Err(GolError::TooSmall {
+                width: WIDTH,
+                height: HEIGHT,
+            });
+        }
         let mut inner = Vec::with_capacity(HEIGHT);
         for row in array {
             inner.push(Vec::from(row));
         }
-        Self { inner }
+        Ok(Self { inner })
+    }
+}
+
+/// Parses the rendered glyphs of [`ALIVE`] and [`DEAD`] cells.
+impl<const WIDTH: usize, const HEIGHT: usize> TryFrom<[[char; WIDTH]; HEIGHT]> for Field {
+    type Error = GolError;
+
+    fn try_from(array: [[char; WIDTH]; HEIGHT]) -> Result<Self, Self::Error> {
+        let mut cells = [[Cell::Dead; WIDTH]; HEIGHT];
+        for (row, glyphs) in cells.iter_mut().zip(array.iter()) {
+            for (cell, glyph) in row.iter_mut().zip(glyphs.iter()) {
+                *cell = Cell::try_from(*glyph)?;
+            }
+        }
+        Self::try_from(cells)
     }
 }
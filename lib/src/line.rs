@@ -0,0 +1,235 @@
+use crate::{Cell, Field, GolError, Grid, State, Topology};
+use std::fmt;
+use std::str::FromStr;
+
+/// Transition function of a one-dimensional automaton on a [`Line`] of [`Cell`]s, or of
+/// other states such as the colours of a [`TotalisticRule`].
+pub trait LineRule<T = Cell> {
+    /// Returns the next state of a cell from its own and those of its left and right neighbour.
+    fn next(&self, left: T, cell: T, right: T) -> T;
+}
+
+/// Wolfram's elementary automaton with the given rule number, e.g. Rule 30 or Rule 110.
+///
+/// Bit `n` of the number is the next state of a cell whose left neighbour, itself and its
+/// right neighbour read as the binary number `n`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ElementaryRule {
+    number: u8,
+}
+
+impl ElementaryRule {
+    pub fn new(number: u8) -> Self {
+        ElementaryRule { number }
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+}
+
+/// Only alive cells are counted, [`Cell::Dying`] cells are treated as dead.
+impl LineRule for ElementaryRule {
+    fn next(&self, left: Cell, cell: Cell, right: Cell) -> Cell {
+        let bit = |cell: Cell| u8::from(cell.is_alive());
+        let pattern = bit(left) << 2 | bit(cell) << 1 | bit(right);
+        if self.number & 1 << pattern != 0 {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        }
+    }
+}
+
+/// Written as Golly does, e.g. `W110`.
+impl fmt::Display for ElementaryRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "W{}", self.number)
+    }
+}
+
+impl FromStr for ElementaryRule {
+    type Err = GolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        s.strip_prefix('W')
+            .or_else(|| s.strip_prefix('w'))
+            .and_then(|number| number.parse().ok())
+            .map(ElementaryRule::new)
+            .ok_or_else(|| GolError::InvalidRule(s.to_string()))
+    }
+}
+
+/// Totalistic automaton with `k` colours, in which the next colour of a cell only depends on
+/// the sum of its own colour and those of its two neighbours.
+///
+/// Colours are the [`State`]s of the cells. Digit `n` of the code written in base `k` is the
+/// next colour of a cell whose sum is `n`, as in Wolfram's numbering.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TotalisticRule {
+    colours: u8,
+    code: u64,
+}
+
+impl TotalisticRule {
+    /// Largest number of colours whose codes fit into a `u64`.
+    pub const MAX_COLOURS: u8 = 7;
+
+    pub fn new(colours: u8, code: u64) -> Result<Self, GolError> {
+        if !(2..=Self::MAX_COLOURS).contains(&colours) {
+            return Err(GolError::InvalidRule(format!(
+                "{} colours aren't between 2 and {}",
+                colours,
+                Self::MAX_COLOURS
+            )));
+        }
+        // Sums go from `0` to `3 * (colours - 1)`, one digit each.
+        let digits = 3 * (u32::from(colours) - 1) + 1;
+        if code >= u64::from(colours).pow(digits) {
+            return Err(GolError::InvalidRule(format!(
+                "code {} has more than {} digits in base {}",
+                code, digits, colours
+            )));
+        }
+        Ok(TotalisticRule { colours, code })
+    }
+
+    pub fn colours(&self) -> u8 {
+        self.colours
+    }
+
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+}
+
+/// Colours beyond those of the rule count as the highest one.
+impl LineRule<State> for TotalisticRule {
+    fn next(&self, left: State, cell: State, right: State) -> State {
+        let colour = |state: State| u32::from(state.0.min(self.colours - 1));
+        let sum = colour(left) + colour(cell) + colour(right);
+        let digit = self.code / u64::from(self.colours).pow(sum) % u64::from(self.colours);
+        State(digit as u8)
+    }
+}
+
+/// Row of cells of a one-dimensional automaton, [`Cell`]s or other states such as the
+/// colours of a [`TotalisticRule`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Line<T = Cell> {
+    cells: Vec<T>,
+}
+
+impl<T: Copy + Default> Line<T> {
+    pub fn new(cells: Vec<T>) -> Result<Self, GolError> {
+        if cells.len() < 3 {
+            return Err(GolError::TooSmall {
+                width: cells.len(),
+                height: 1,
+            });
+        }
+        Ok(Line { cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns the next generation. Cells beyond the ends of a bounded line are in the default
+    /// state, a torus joins both ends into a ring.
+    pub fn advance<R: LineRule<T> + ?Sized>(&self, rule: &R, topology: Topology) -> Line<T> {
+        let width = self.width();
+        let neighbour = |index: Option<usize>| match (index, topology) {
+            (Some(index), _) if index < width => self.cells[index],
+            (Some(_), Topology::Torus) => self.cells[0],
+            (None, Topology::Torus) => self.cells[width - 1],
+            (_, Topology::Bounded) => T::default(),
+        };
+        let cells = (0..width)
+            .map(|x| {
+                let (left, right) = (neighbour(x.checked_sub(1)), neighbour(Some(x + 1)));
+                rule.next(left, self.cells[x], right)
+            })
+            .collect();
+        Line { cells }
+    }
+
+    /// Cells of the given number of generations starting with this one, row after row.
+    fn generations<R: LineRule<T> + ?Sized>(
+        &self,
+        rule: &R,
+        topology: Topology,
+        generations: usize,
+    ) -> Vec<T> {
+        let mut cells = Vec::with_capacity(self.width() * generations);
+        let mut line = self.clone();
+        for y in 0..generations {
+            if y > 0 {
+                line = line.advance(rule, topology);
+            }
+            cells.extend_from_slice(&line.cells);
+        }
+        cells
+    }
+}
+
+impl Line {
+    /// Dead line with a single alive cell in the middle, the usual start of a space-time
+    /// diagram.
+    pub fn single(width: usize) -> Result<Self, GolError> {
+        let mut line = Line::new(vec![Cell::Dead; width])?;
+        line.cells[width / 2] = Cell::Alive;
+        Ok(line)
+    }
+
+    /// Renders the given number of generations starting with this one as the rows of a
+    /// [`Field`], with time running downwards.
+    pub fn space_time<R: LineRule + ?Sized>(
+        &self,
+        rule: &R,
+        topology: Topology,
+        generations: usize,
+    ) -> Result<Field, GolError> {
+        let mut field = Field::try_dead(self.width(), generations)?;
+        let cells = self.generations(rule, topology, generations);
+        for (row, cells) in field.rows_mut().into_iter().zip(cells.chunks(self.width())) {
+            row.copy_from_slice(cells);
+        }
+        Ok(field)
+    }
+}
+
+impl Line<State> {
+    /// Renders the given number of generations starting with this one as the rows of a
+    /// [`Grid`], with time running downwards.
+    pub fn space_time<R: LineRule<State> + ?Sized>(
+        &self,
+        rule: &R,
+        topology: Topology,
+        generations: usize,
+    ) -> Result<Grid<State>, GolError> {
+        let mut grid = Grid::filled(self.width(), generations, State::DEAD)?;
+        let cells = self.generations(rule, topology, generations);
+        grid.cells_mut().copy_from_slice(&cells);
+        Ok(grid)
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyphs = self.cells.iter().map(|cell| cell.glyph());
+        writeln!(f, "{}", glyphs.collect::<String>())
+    }
+}
+
+impl fmt::Display for Line<State> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyphs = self.cells.iter().map(|state| state.glyph());
+        writeln!(f, "{}", glyphs.collect::<String>())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Cell, Field, GolError, Topology};
+
+/// Reversible block rule on the Margolus neighbourhood, which splits the field into 2x2 blocks
+/// and replaces each block as a whole. The blocks start one cell further right and down every
+/// other generation, so that information crosses their borders.
+///
+/// A block is numbered by its alive cells, bit `0` being the top left, `1` the top right, `2`
+/// the bottom left and `3` the bottom right cell, and the table maps every number to the next
+/// one. Each block has to have a different next block, which makes the rule invertible.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BlockRule {
+    table: [u8; 16],
+}
+
+impl BlockRule {
+    /// Critters: blocks with two alive cells stay as they are, all others are inverted, and
+    /// those which had three alive cells are also rotated by 180 degrees.
+    pub const CRITTERS: BlockRule = BlockRule {
+        table: [15, 14, 13, 3, 11, 5, 6, 1, 7, 9, 10, 2, 12, 4, 8, 0],
+    };
+
+    /// Tron: blocks of only alive or only dead cells are inverted, all others stay as they are.
+    pub const TRON: BlockRule = BlockRule {
+        table: [15, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 0],
+    };
+
+    /// Billiard-ball model: a single alive cell moves to the opposite corner, two alive cells
+    /// on one diagonal turn to the other one, and all other blocks stay as they are.
+    pub const BILLIARD_BALL: BlockRule = BlockRule {
+        table: [0, 8, 4, 3, 2, 5, 9, 7, 1, 6, 10, 11, 12, 13, 14, 15],
+    };
+
+    pub fn new(table: [u8; 16]) -> Result<Self, GolError> {
+        let mut seen = [false; 16];
+        for &next in &table {
+            if next >= 16 {
+                return Err(GolError::InvalidRule(format!(
+                    "{} is not a block of 4 cells",
+                    next
+                )));
+            }
+            if seen[usize::from(next)] {
+                return Err(GolError::InvalidRule(format!(
+                    "block {} is the next one of more than one block, so the rule isn't reversible",
+                    next
+                )));
+            }
+            seen[usize::from(next)] = true;
+        }
+        Ok(BlockRule { table })
+    }
+
+    /// Next block of every block, see [`BlockRule`] for their numbering.
+    pub fn table(&self) -> [u8; 16] {
+        self.table
+    }
+
+    /// Rule which takes every block back to the one it came from.
+    pub fn inverse(&self) -> BlockRule {
+        let mut table = [0; 16];
+        for (block, &next) in self.table.iter().enumerate() {
+            table[usize::from(next)] = block as u8;
+        }
+        BlockRule { table }
+    }
+
+    /// Returns the field with every block replaced, where `shifted` blocks start at `(1, 1)`
+    /// instead of `(0, 0)`.
+    ///
+    /// Only alive cells are counted, [`Cell::Dying`] cells are treated as dead. Blocks sticking
+    /// out of a bounded field stay as they are, as do blocks which would wrap around a torus of
+    /// odd width or height, since those can't be split into blocks evenly.
+    pub fn advance(&self, field: &Field, shifted: bool, topology: Topology) -> Field {
+        let (width, height) = (field.width(), field.height());
+        let offset = usize::from(shifted);
+        let wraps = |len: usize| topology == Topology::Torus && len.is_multiple_of(2);
+        let last = |len: usize| if wraps(len) { len } else { len - 1 };
+
+        let mut next = field.clone();
+        for y in (offset..last(height)).step_by(2) {
+            for x in (offset..last(width)).step_by(2) {
+                let cells = [
+                    (x, y),
+                    ((x + 1) % width, y),
+                    (x, (y + 1) % height),
+                    ((x + 1) % width, (y + 1) % height),
+                ];
+                let block = cells.iter().enumerate().fold(0, |block, (bit, &cords)| {
+                    block | u8::from(field.value(cords).is_alive()) << bit
+                });
+                let block = self.table[usize::from(block)];
+                for (bit, &cords) in cells.iter().enumerate() {
+                    *next.value_mut(cords) = if block & 1 << bit != 0 {
+                        Cell::Alive
+                    } else {
+                        Cell::Dead
+                    };
+                }
+            }
+        }
+        next
+    }
+}
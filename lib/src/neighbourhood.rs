@@ -0,0 +1,75 @@
+use crate::Field;
+use std::fmt;
+
+/// Cells around a cell which count as its neighbours.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Neighbourhood {
+    /// All cells within the given Chebyshev distance, the 8 surrounding cells at range `1`.
+    Moore(usize),
+    /// All cells within the given Manhattan distance, the 4 orthogonal cells at range `1`.
+    VonNeumann(usize),
+    /// The 6 neighbours of a hexagonal grid emulated on the square one, which are the
+    /// surrounding cells except the top right and bottom left ones. Rows are seen as shifted
+    /// half a cell to the left of the row above, see [`Field::display_hex`].
+    Hexagonal,
+}
+
+impl Neighbourhood {
+    /// Largest distance of a neighbour along either axis.
+    pub fn range(self) -> usize {
+        match self {
+            Neighbourhood::Moore(range) | Neighbourhood::VonNeumann(range) => range,
+            Neighbourhood::Hexagonal => 1,
+        }
+    }
+
+    /// Offsets of the neighbours in reading order.
+    pub fn offsets(self) -> Vec<(isize, isize)> {
+        let range = self.range() as isize;
+        let mut offsets = Vec::new();
+        for y in -range..=range {
+            for x in -range..=range {
+                let neighbour = match self {
+                    Neighbourhood::Moore(_) => true,
+                    Neighbourhood::VonNeumann(_) => x.abs() + y.abs() <= range,
+                    Neighbourhood::Hexagonal => (x, y) != (1, -1) && (x, y) != (-1, 1),
+                };
+                if neighbour && (x, y) != (0, 0) {
+                    offsets.push((x, y));
+                }
+            }
+        }
+        offsets
+    }
+
+    /// Number of neighbours of a cell.
+    pub fn size(self) -> usize {
+        self.offsets().len()
+    }
+}
+
+/// The 8 surrounding cells.
+impl Default for Neighbourhood {
+    fn default() -> Self {
+        Neighbourhood::Moore(1)
+    }
+}
+
+/// Renders a [`Field`] as a hexagonal grid, see [`Field::display_hex`].
+pub struct HexDisplay<'a> {
+    pub(crate) field: &'a Field,
+}
+
+impl fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = (self.field.width(), self.field.height());
+        for y in 0..height {
+            // Each row is shifted half a cell, which is one character, left of the row above.
+            let row = (0..width)
+                .map(|x| self.field.value((x, y)).glyph().to_string())
+                .collect::<Vec<_>>();
+            writeln!(f, "{}{}", " ".repeat(height - 1 - y), row.join(" "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,374 @@
+use crate::{Cell, DyingState, GolError, Neighbourhood};
+use std::fmt;
+use std::str::FromStr;
+
+/// Largest number of neighbours a rule can count, one bit of a mask per count.
+const MAX_NEIGHBOURS: usize = 127;
+
+/// Life-like rule in B/S notation, e.g. `B36/S23` for HighLife, or Generations rule in B/S/C
+/// notation, e.g. `B2/S/C3` for Brian's Brain.
+///
+/// Rules on the von Neumann or hexagonal neighbourhood end in `V` or `H`, e.g. `B2/S013V`.
+/// Higher ranges use the notation `R2,C0,S2-3,B3,NN`, where `NM` and `NN` are the Moore and
+/// von Neumann neighbourhoods and `C0` stands for two states. Neighbourhoods of more than 127
+/// cells, like the Moore neighbourhood from range 6 on, are rejected, see
+/// [`LargerThanLife`](crate::LargerThanLife) for those.
+///
+/// Each bit `n` of `birth` and `survival` stands for `n` alive neighbours.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Rule {
+    birth: u128,
+    survival: u128,
+    /// Number of states including dead and alive, cells which don't survive pass through the
+    /// states in between as [`Cell::Dying`].
+    states: u8,
+    neighbourhood: Neighbourhood,
+}
+
+impl Rule {
+    /// Conway's Game of Life `B3/S23`:
+    /// 1. Any live cell with fewer than two live neighbours dies, as if by underpopulation.
+    /// 2. Any live cell with two or three live neighbours lives on to the next generation.
+    /// 3. Any live cell with more than three live neighbours dies, as if by overpopulation.
+    /// 4. Any dead cell with exactly three live neighbours becomes a live cell, as if by reproduction.
+    pub const CONWAY: Rule = Rule {
+        birth: 1 << 3,
+        survival: 1 << 2 | 1 << 3,
+        states: 2,
+        neighbourhood: Neighbourhood::Moore(1),
+    };
+
+    /// Brian's Brain `B2/S/C3`: every alive cell dies, and stays refractory for one generation.
+    pub const BRIANS_BRAIN: Rule = Rule {
+        birth: 1 << 2,
+        survival: 0,
+        states: 3,
+        neighbourhood: Neighbourhood::Moore(1),
+    };
+
+    /// Star Wars `B2/S345/C4`.
+    pub const STAR_WARS: Rule = Rule {
+        birth: 1 << 2,
+        survival: 1 << 3 | 1 << 4 | 1 << 5,
+        states: 4,
+        neighbourhood: Neighbourhood::Moore(1),
+    };
+
+    pub fn new(birth: &[usize], survival: &[usize]) -> Result<Self, GolError> {
+        Self::with_counts(birth, survival, 2, Neighbourhood::default())
+    }
+
+    /// Generations rule with the given number of states, of which `2` are dead and alive.
+    pub fn generations(birth: &[usize], survival: &[usize], states: u8) -> Result<Self, GolError> {
+        if states < 2 {
+            return Err(GolError::InvalidRule(format!(
+                "{} states don't include dead and alive",
+                states
+            )));
+        }
+        Self::with_counts(birth, survival, states, Neighbourhood::default())
+    }
+
+    fn with_counts(
+        birth: &[usize],
+        survival: &[usize],
+        states: u8,
+        neighbourhood: Neighbourhood,
+    ) -> Result<Self, GolError> {
+        let size = neighbourhood.size();
+        if size == 0 || size > MAX_NEIGHBOURS {
+            return Err(GolError::InvalidRule(format!(
+                "{:?} has {} neighbours, not between 1 and {}",
+                neighbourhood, size, MAX_NEIGHBOURS
+            )));
+        }
+        let mask = |counts: &[usize]| {
+            counts.iter().try_fold(0u128, |mask, &count| {
+                if count > size {
+                    Err(GolError::InvalidRule(format!(
+                        "{} exceeds the {} neighbours of a cell",
+                        count, size
+                    )))
+                } else {
+                    Ok(mask | 1 << count)
+                }
+            })
+        };
+        Ok(Rule {
+            birth: mask(birth)?,
+            survival: mask(survival)?,
+            states,
+            neighbourhood,
+        })
+    }
+
+    /// The same rule counting the alive cells of another neighbourhood.
+    pub fn with_neighbourhood(&self, neighbourhood: Neighbourhood) -> Result<Self, GolError> {
+        Self::with_counts(
+            &counts(self.birth),
+            &counts(self.survival),
+            self.states,
+            neighbourhood,
+        )
+    }
+
+    /// Cells whose alive ones are counted, the 8 surrounding cells for life-like rules.
+    pub fn neighbourhood(&self) -> Neighbourhood {
+        self.neighbourhood
+    }
+
+    /// Number of states including dead and alive, `2` for life-like rules.
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    /// Fails for rules which can't be advanced on an unbounded field: those giving birth to
+    /// cells without alive neighbours, which would fill the infinite plane, Generations rules
+    /// and rules on other neighbourhoods than the 8 surrounding cells.
+    pub fn check_unbounded(&self) -> Result<(), GolError> {
+        if self.next(Cell::Dead, 0) == Cell::Alive {
+            return Err(GolError::InvalidRule(format!(
+                "{} gives birth to cells without alive neighbours on an unbounded field",
+                self
+            )));
+        }
+        if self.states > 2 {
+            return Err(GolError::InvalidRule(format!(
+                "{} has dying cells, which an unbounded field doesn't track",
+                self
+            )));
+        }
+        if self.neighbourhood != Neighbourhood::Moore(1) {
+            return Err(GolError::InvalidRule(format!(
+                "{} doesn't count the 8 surrounding cells",
+                self
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the next state of a cell with the given amount of alive neighbours.
+    pub fn next(&self, cell: Cell, alive: usize) -> Cell {
+        let (mask, dying) = match cell {
+            Cell::Dead => (self.birth, Cell::Dead),
+            Cell::Alive => (self.survival, self.decay(1)),
+            Cell::Dying(state) => return self.decay(state.get()),
+        };
+        if alive <= MAX_NEIGHBOURS && mask & 1 << alive != 0 {
+            Cell::Alive
+        } else {
+            dying
+        }
+    }
+
+    /// State following the given one of a cell which isn't alive anymore.
+    pub(crate) fn decay(&self, state: u8) -> Cell {
+        match state.checked_add(1) {
+            Some(next) if next < self.states => {
+                DyingState::new(next).map_or(Cell::Dead, Cell::Dying)
+            }
+            _ => Cell::Dead,
+        }
+    }
+}
+
+/// Counts whose bits are set in the mask, in ascending order.
+fn counts(mask: u128) -> Vec<usize> {
+    (0..=MAX_NEIGHBOURS)
+        .filter(|count| mask & 1 << count != 0)
+        .collect()
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::CONWAY
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = self.neighbourhood.range();
+        if range > 1 {
+            // Runs of consecutive counts are written as ranges, e.g. `2-3,5`.
+            let list = |mask: u128| {
+                let mut runs: Vec<(usize, usize)> = Vec::new();
+                for count in counts(mask) {
+                    match runs.last_mut() {
+                        Some((_, end)) if *end + 1 == count => *end = count,
+                        _ => runs.push((count, count)),
+                    }
+                }
+                let runs = runs.into_iter().map(|(start, end)| {
+                    if start == end {
+                        start.to_string()
+                    } else {
+                        format!("{}-{}", start, end)
+                    }
+                });
+                runs.collect::<Vec<_>>().join(",")
+            };
+            let states = if self.states == 2 { 0 } else { self.states };
+            let neighbourhood = match self.neighbourhood {
+                Neighbourhood::VonNeumann(_) => 'N',
+                _ => 'M',
+            };
+            return write!(
+                f,
+                "R{},C{},S{},B{},N{}",
+                range,
+                states,
+                list(self.survival),
+                list(self.birth),
+                neighbourhood
+            );
+        }
+
+        let digits = |mask: u128| {
+            counts(mask)
+                .into_iter()
+                .map(|count| char::from(b'0' + count as u8))
+                .collect::<String>()
+        };
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survival))?;
+        if self.states > 2 {
+            write!(f, "/C{}", self.states)?;
+        }
+        match self.neighbourhood {
+            Neighbourhood::VonNeumann(_) => write!(f, "V"),
+            Neighbourhood::Hexagonal => write!(f, "H"),
+            Neighbourhood::Moore(_) => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = GolError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.starts_with(|first: char| first.eq_ignore_ascii_case(&'R')) {
+            return parse_higher_range(s);
+        }
+
+        let invalid = || GolError::InvalidRule(s.to_string());
+        let counts = |part: &str, prefix: char| {
+            let digits = part
+                .strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))
+                .ok_or_else(invalid)?;
+            digits
+                .chars()
+                .map(|digit| digit.to_digit(10).map(|count| count as usize))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(invalid)
+        };
+
+        let (rest, neighbourhood) = match s.chars().last().map(|last| last.to_ascii_uppercase()) {
+            Some('V') => (&s[..s.len() - 1], Neighbourhood::VonNeumann(1)),
+            Some('H') => (&s[..s.len() - 1], Neighbourhood::Hexagonal),
+            _ => (s, Neighbourhood::default()),
+        };
+        let mut parts = rest.split('/');
+        let (birth, survival) = match (parts.next(), parts.next()) {
+            (Some(birth), Some(survival)) => (counts(birth, 'B')?, counts(survival, 'S')?),
+            _ => return Err(invalid()),
+        };
+        let states = match (parts.next(), parts.next()) {
+            (None, _) => 2,
+            (Some(states), None) => states
+                .strip_prefix('C')
+                .or_else(|| states.strip_prefix('c'))
+                .and_then(|states| states.parse().ok())
+                .ok_or_else(invalid)?,
+            _ => return Err(invalid()),
+        };
+        if states < 2 {
+            return Rule::generations(&birth, &survival, states);
+        }
+        Rule::with_counts(&birth, &survival, states, neighbourhood)
+    }
+}
+
+/// Parses the higher range notation `R<range>,C<states>,S<counts>,B<counts>,N<M|N>`, where
+/// the counts are separated by commas and may be ranges like `2-3`.
+fn parse_higher_range(s: &str) -> Result<Rule, GolError> {
+    let invalid = || GolError::InvalidRule(s.to_string());
+    let number = |digits: &str| digits.parse::<usize>().map_err(|_| invalid());
+    // The neighbourhood may only follow the counts, but no rule counts more neighbours.
+    let count = |digits: &str| match number(digits)? {
+        count if count > MAX_NEIGHBOURS => Err(GolError::InvalidRule(format!(
+            "{} exceeds the {} neighbours a rule can count",
+            count, MAX_NEIGHBOURS
+        ))),
+        count => Ok(count),
+    };
+
+    let (mut range, mut states, mut von_neumann) = (None, 2, false);
+    let (mut survival, mut birth) = (None, None);
+    // Counts without a letter continue the list of the last `S` or `B`.
+    let mut survival_list = true;
+    for item in s.split(',') {
+        let mut chars = item.chars();
+        let letter = chars.next().ok_or_else(invalid)?.to_ascii_uppercase();
+        let value = chars.as_str();
+        let list = match letter {
+            'R' => {
+                range = Some(number(value)?);
+                continue;
+            }
+            'C' => {
+                states = match number(value)? {
+                    0 | 2 => 2,
+                    states if (3..=usize::from(u8::MAX)).contains(&states) => states as u8,
+                    _ => return Err(invalid()),
+                };
+                continue;
+            }
+            'N' => {
+                von_neumann = match value {
+                    "M" | "m" => false,
+                    "N" | "n" => true,
+                    _ => return Err(invalid()),
+                };
+                continue;
+            }
+            'S' => {
+                survival_list = true;
+                survival.get_or_insert_with(Vec::new)
+            }
+            'B' => {
+                survival_list = false;
+                birth.get_or_insert_with(Vec::new)
+            }
+            _ if letter.is_ascii_digit() => {
+                let list = if survival_list {
+                    survival.as_mut()
+                } else {
+                    birth.as_mut()
+                };
+                list.ok_or_else(invalid)?
+            }
+            _ => return Err(invalid()),
+        };
+        let value = if letter.is_ascii_digit() { item } else { value };
+        if value.is_empty() {
+            continue;
+        }
+        match value.split_once('-') {
+            Some((first, last)) => list.extend(count(first)?..=count(last)?),
+            None => list.push(count(value)?),
+        }
+    }
+
+    match (range, survival, birth) {
+        (Some(range), Some(survival), Some(birth)) if range > 0 => {
+            let neighbourhood = if von_neumann {
+                Neighbourhood::VonNeumann(range)
+            } else {
+                Neighbourhood::Moore(range)
+            };
+            Rule::with_counts(&birth, &survival, states, neighbourhood)
+        }
+        _ => Err(invalid()),
+    }
+}
@@ -0,0 +1,157 @@
+//! Serde support of the library types.
+//!
+//! A [`Field`] is encoded as its dimensions and a bitmap of the alive cells in row-major order,
+//! which is base64 encoded for human-readable formats like JSON. Fields with
+//! [`Cell::Dying`] cells additionally carry the [`State`] of every cell.
+
+use crate::{Cell, Field, Rule, State};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+
+#[derive(Serialize, Deserialize)]
+struct EncodedField<Cells> {
+    width: usize,
+    height: usize,
+    cells: Cells,
+    // Binary formats can't tell a skipped field from the end of the data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    states: Option<Cells>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EncodedBinaryField {
+    width: usize,
+    height: usize,
+    cells: Vec<u8>,
+    states: Option<Vec<u8>>,
+}
+
+impl Serialize for Field {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bitmap = vec![0u8; (self.width() * self.height()).div_ceil(8)];
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                if self.value((x, y)).is_alive() {
+                    let index = y * self.width() + x;
+                    bitmap[index / 8] |= 1 << (index % 8);
+                }
+            }
+        }
+
+        let (width, height) = (self.width(), self.height());
+        let states = self
+            .inner
+            .iter()
+            .flatten()
+            .any(|cell| matches!(cell, Cell::Dying(_)))
+            .then(|| {
+                self.inner
+                    .iter()
+                    .flatten()
+                    .map(|&cell| State::from(cell).0)
+                    .collect::<Vec<_>>()
+            });
+        if serializer.is_human_readable() {
+            EncodedField {
+                width,
+                height,
+                cells: STANDARD.encode(&bitmap),
+                states: states.map(|states| STANDARD.encode(states)),
+            }
+            .serialize(serializer)
+        } else {
+            EncodedBinaryField {
+                width,
+                height,
+                cells: bitmap,
+                states,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (width, height, bitmap, states) = if deserializer.is_human_readable() {
+            let encoded = EncodedField::<String>::deserialize(deserializer)?;
+            let bitmap = STANDARD.decode(&encoded.cells).map_err(D::Error::custom)?;
+            let states = match encoded.states {
+                Some(states) => Some(STANDARD.decode(states).map_err(D::Error::custom)?),
+                None => None,
+            };
+            (encoded.width, encoded.height, bitmap, states)
+        } else {
+            let encoded = EncodedBinaryField::deserialize(deserializer)?;
+            (encoded.width, encoded.height, encoded.cells, encoded.states)
+        };
+
+        let expected = width
+            .checked_mul(height)
+            .map(|cells| cells.div_ceil(8))
+            .ok_or_else(|| D::Error::custom("field dimensions overflow"))?;
+        if bitmap.len() != expected {
+            return Err(D::Error::custom(format!(
+                "expected {} bytes of cells for a {}x{} field, got {}",
+                expected,
+                width,
+                height,
+                bitmap.len()
+            )));
+        }
+
+        if let Some(states) = &states {
+            if states.len() != width * height {
+                return Err(D::Error::custom(format!(
+                    "expected {} states for a {}x{} field, got {}",
+                    width * height,
+                    width,
+                    height,
+                    states.len()
+                )));
+            }
+        }
+
+        let mut field = Field::try_dead(width, height).map_err(D::Error::custom)?;
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                let alive = bitmap[index / 8] & 1 << (index % 8) != 0;
+                *field.value_mut((x, y)) = match &states {
+                    Some(states) => {
+                        let cell =
+                            Cell::try_from(State(states[index])).map_err(D::Error::custom)?;
+                        if cell.is_alive() != alive {
+                            return Err(D::Error::custom(format!(
+                                "state of ({}, {}) contradicts the bitmap",
+                                x, y
+                            )));
+                        }
+                        cell
+                    }
+                    None if alive => Cell::Alive,
+                    None => Cell::Dead,
+                };
+            }
+        }
+        Ok(field)
+    }
+}
+
+/// Rules are stored as their rulestring.
+impl Serialize for Rule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Field, Probability, Rule, Topology, UpdateMode};
+
+/// State of a simulation which is enough to resume it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub field: Field,
+    /// Number of generations advanced since the initial field.
+    pub generation: u64,
+    pub rule: Rule,
+    pub topology: Topology,
+    /// Seed the initial field was generated from, if it was random.
+    pub seed: Option<u64>,
+    /// Order in which the cells of a generation are updated.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub update_mode: UpdateMode,
+    /// Probabilities of a cell being born and of surviving when the rule says so.
+    #[cfg_attr(feature = "serde", serde(default = "certain"))]
+    pub probabilities: (Probability, Probability),
+    /// Seed the random choices of stochastic updates are drawn from, anew each generation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rng_seed: Option<u64>,
+}
+
+/// Snapshots saved before stochastic updates existed were deterministic.
+#[cfg(feature = "serde")]
+fn certain() -> (Probability, Probability) {
+    (Probability::ALWAYS, Probability::ALWAYS)
+}
+
+impl Snapshot {
+    /// Snapshot of an initial field under Conway's rules on a bounded field, with synchronous
+    /// deterministic updates.
+    pub fn new(field: Field) -> Self {
+        Snapshot {
+            field,
+            generation: 0,
+            rule: Rule::default(),
+            topology: Topology::default(),
+            seed: None,
+            update_mode: UpdateMode::default(),
+            probabilities: (Probability::ALWAYS, Probability::ALWAYS),
+            rng_seed: None,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Cell, Field, GolError, Rule};
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Unbounded field which only stores the coordinates of alive cells.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SparseField {
+    alive: HashSet<(i64, i64)>,
+}
+
+impl SparseField {
+    /// Largest width and height of the alive cells which [`Display`](fmt::Display) renders
+    /// cell by cell.
+    pub const MAX_DISPLAYED: usize = 256;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of alive cells.
+    pub fn population(&self) -> usize {
+        self.alive.len()
+    }
+
+    pub fn get(&self, cords: (i64, i64)) -> Cell {
+        if self.alive.contains(&cords) {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        }
+    }
+
+    /// Only alive and dead cells are tracked, so [`Cell::Dying`] cells are rejected.
+    pub fn set(&mut self, cords: (i64, i64), cell: Cell) -> Result<(), GolError> {
+        match cell {
+            Cell::Alive => self.alive.insert(cords),
+            Cell::Dead => self.alive.remove(&cords),
+            Cell::Dying(state) => return Err(GolError::InvalidState(state.get())),
+        };
+        Ok(())
+    }
+
+    /// Coordinates of all alive cells in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.alive.iter().copied()
+    }
+
+    /// Smallest and largest coordinates of alive cells, `None` if all cells are dead.
+    pub fn bounding_box(&self) -> Option<((i64, i64), (i64, i64))> {
+        let mut cells = self.iter();
+        let first = cells.next()?;
+        Some(cells.fold(
+            (first, first),
+            |((min_x, min_y), (max_x, max_y)), (x, y)| {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            },
+        ))
+    }
+
+    /// Copies the given area starting at `origin` into a dense [`Field`].
+    pub fn to_field(
+        &self,
+        origin: (i64, i64),
+        width: usize,
+        height: usize,
+    ) -> Result<Field, GolError> {
+        let mut field = Field::try_dead(width, height)?;
+        for (x, y) in self.iter() {
+            let column = x.wrapping_sub(origin.0) as u64;
+            let row = y.wrapping_sub(origin.1) as u64;
+            if column < width as u64 && row < height as u64 {
+                *field.value_mut((column as usize, row as usize)) = Cell::Alive;
+            }
+        }
+        Ok(field)
+    }
+
+    /// Returns the next generation under the given rule.
+    ///
+    /// Only alive cells and their neighbours are visited. Fails for rules which aren't
+    /// supported on an unbounded field, see [`Rule::check_unbounded`].
+    pub fn advance_with(&self, rule: &Rule) -> Result<SparseField, GolError> {
+        rule.check_unbounded()?;
+
+        let mut neighbours = HashMap::<(i64, i64), usize>::with_capacity(self.alive.len() * 8);
+        for &(x, y) in &self.alive {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if dx != 0 || dy != 0 {
+                        *neighbours
+                            .entry((x.wrapping_add(dx), y.wrapping_add(dy)))
+                            .or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        let mut alive = HashSet::with_capacity(self.alive.len());
+        // Alive cells without alive neighbours don't show up in the counts.
+        for &cords in &self.alive {
+            if !neighbours.contains_key(&cords) && rule.next(Cell::Alive, 0) == Cell::Alive {
+                alive.insert(cords);
+            }
+        }
+        for (cords, count) in neighbours {
+            if rule.next(self.get(cords), count) == Cell::Alive {
+                alive.insert(cords);
+            }
+        }
+        Ok(SparseField { alive })
+    }
+}
+
+/// Places the top left cell of the field at `(0, 0)`. Fails for fields with
+/// [`Cell::Dying`] cells, see [`SparseField::set`].
+impl TryFrom<&Field> for SparseField {
+    type Error = GolError;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        let mut sparse = SparseField::new();
+        for y in 0..field.height() {
+            for x in 0..field.width() {
+                sparse.set((x as i64, y as i64), *field.value((x, y)))?;
+            }
+        }
+        Ok(sparse)
+    }
+}
+
+impl fmt::Display for SparseField {
+    /// Renders the bounding box of all alive cells, or summarises it if it's larger than
+    /// [`SparseField::MAX_DISPLAYED`] cells along either side.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounding_box().unwrap_or(((0, 0), (0, 0)));
+        // Coordinates may span the whole range of `i64`.
+        let width = i128::from(max_x) - i128::from(min_x) + 1;
+        let height = i128::from(max_y) - i128::from(min_y) + 1;
+        let limit = Self::MAX_DISPLAYED as i128;
+        if width > limit || height > limit {
+            return writeln!(
+                f,
+                "{} alive cells from ({}, {}) to ({}, {})",
+                self.population(),
+                min_x,
+                min_y,
+                max_x,
+                max_y
+            );
+        }
+        match self.to_field(
+            (min_x, min_y),
+            width.max(3) as usize,
+            height.max(3) as usize,
+        ) {
+            Ok(field) => write!(f, "{}", field),
+            Err(why) => write!(f, "{}", why),
+        }
+    }
+}
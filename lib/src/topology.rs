@@ -0,0 +1,10 @@
+/// How the edges of a [`Field`](crate::Field) are connected.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    /// Cells beyond the edges are treated as dead.
+    #[default]
+    Bounded,
+    /// Opposite edges are neighbours of each other.
+    Torus,
+}
@@ -0,0 +1,37 @@
+use crate::{Cell, Neighbourhood, Rule};
+
+/// Transition function of a cellular automaton on a [`Field`](crate::Field), or on a
+/// [`Grid`](crate::Grid) of other states such as [`State`](crate::State)s.
+pub trait Transition<C: Default = Cell> {
+    /// Returns the next state of a cell from its current state and those of its neighbours.
+    ///
+    /// The neighbours are given in the order of [`Neighbourhood::offsets`].
+    fn transition(&self, cell: C, neighbours: &[C]) -> C;
+
+    /// Cells passed as neighbours to [`Transition::transition`].
+    fn neighbourhood(&self) -> Neighbourhood {
+        Neighbourhood::default()
+    }
+
+    /// State an alive cell passes to when it doesn't survive, e.g. when survival is left to
+    /// chance.
+    fn death(&self) -> C {
+        C::default()
+    }
+}
+
+/// Life-like and Generations rules only count the alive neighbours.
+impl Transition for Rule {
+    fn transition(&self, cell: Cell, neighbours: &[Cell]) -> Cell {
+        let alive = neighbours.iter().filter(|cell| cell.is_alive()).count();
+        self.next(cell, alive)
+    }
+
+    fn neighbourhood(&self) -> Neighbourhood {
+        Rule::neighbourhood(self)
+    }
+
+    fn death(&self) -> Cell {
+        self.decay(1)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::GolError;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Probability from `0` to `1`. Unlike a bare [`f64`] it is never NaN, so it can be compared
+/// for equality.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "f64", try_from = "f64")
+)]
+pub struct Probability(f64);
+
+impl Eq for Probability {}
+
+impl Probability {
+    pub const NEVER: Probability = Probability(0.0);
+    pub const ALWAYS: Probability = Probability(1.0);
+
+    /// Fails for anything outside of `0` to `1`, which includes NaN.
+    pub fn new(probability: f64) -> Result<Self, GolError> {
+        if (0.0..=1.0).contains(&probability) {
+            Ok(Probability(probability))
+        } else {
+            Err(GolError::InvalidProbability(probability.to_string()))
+        }
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for Probability {
+    type Error = GolError;
+
+    fn try_from(probability: f64) -> Result<Self, Self::Error> {
+        Probability::new(probability)
+    }
+}
+
+impl From<Probability> for f64 {
+    fn from(probability: Probability) -> Self {
+        probability.0
+    }
+}
+
+impl fmt::Display for Probability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Order in which the cells of a generation are updated.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UpdateMode {
+    /// All cells update at once from the previous generation.
+    #[default]
+    Synchronous,
+    /// Cells update one at a time in a new random order each generation, seeing the cells
+    /// updated before them.
+    RandomSequential,
+    /// Every cell updates from the previous generation with the given probability, and keeps
+    /// its state otherwise.
+    Asynchronous { rate: Probability },
+}
+
+impl UpdateMode {
+    /// Asynchronous updates at the given rate, which has to be a probability.
+    pub fn asynchronous(rate: f64) -> Result<Self, GolError> {
+        Ok(UpdateMode::Asynchronous {
+            rate: Probability::new(rate)?,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{State, Transition};
+
+/// Wireworld, in which electrons travel along conductors to build digital logic.
+///
+/// Its four states are [`Wireworld::EMPTY`], [`Wireworld::HEAD`], [`Wireworld::TAIL`] and
+/// [`Wireworld::CONDUCTOR`], advanced on a [`Grid`](crate::Grid) of [`State`]s.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Wireworld;
+
+impl Wireworld {
+    pub const EMPTY: State = State(0);
+    pub const HEAD: State = State(1);
+    pub const TAIL: State = State(2);
+    pub const CONDUCTOR: State = State(3);
+}
+
+/// 1. Empty cells stay empty.
+/// 2. An electron head becomes an electron tail.
+/// 3. An electron tail becomes a conductor.
+/// 4. A conductor becomes an electron head if one or two of its neighbours are electron heads.
+impl Transition<State> for Wireworld {
+    fn transition(&self, cell: State, neighbours: &[State]) -> State {
+        match cell {
+            Wireworld::HEAD => Wireworld::TAIL,
+            Wireworld::TAIL => Wireworld::CONDUCTOR,
+            Wireworld::CONDUCTOR => {
+                let heads = neighbours.iter().filter(|&&cell| cell == Wireworld::HEAD);
+                match heads.count() {
+                    1 | 2 => Wireworld::HEAD,
+                    _ => Wireworld::CONDUCTOR,
+                }
+            }
+            _ => Wireworld::EMPTY,
+        }
+    }
+}
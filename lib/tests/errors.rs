@@ -0,0 +1,90 @@
+use gol_lib::{Cell, DyingState, Field, GolError, Grid, Rule, State, Topology, ALIVE, DEAD};
+use std::convert::TryFrom;
+
+#[test]
+fn test_too_small() {
+    assert_eq!(
+        Field::try_dead(2, 5),
+        Err(GolError::TooSmall {
+            width: 2,
+            height: 5
+        })
+    );
+    assert_eq!(
+        Field::try_from([[DEAD; 0]; 0]),
+        Err(GolError::TooSmall {
+            width: 0,
+            height: 0
+        })
+    );
+    assert!(Field::try_from([[ALIVE, DEAD]; 3]).is_err());
+}
+
+#[test]
+fn test_out_of_bounds() {
+    let mut field = Field::dead(3, 4);
+
+    let error = GolError::OutOfBounds {
+        cords: (3, 0),
+        width: 3,
+        height: 4,
+    };
+    assert_eq!(field.get((3, 0)), Err(error.clone()));
+    assert_eq!(
+        field.get_mut((3, 0)).map(|value| *value),
+        Err(error.clone())
+    );
+    assert_eq!(field.neighbours((3, 0)), Err(error.clone()));
+    assert_eq!(field.advance_one((3, 0)), Err(error));
+    assert!(field.advance_row(4).is_err());
+    assert!(field.band(4, 3).is_err());
+    assert!(field.band(0, 5).is_err());
+    assert!(field.band(3, 2).is_err());
+    assert_eq!(field.band(3, 4).map(|band| band.height()), Ok(4));
+
+    *field.get_mut((2, 3)).unwrap() = Cell::Alive;
+    assert_eq!(field.get((2, 3)), Ok(&Cell::Alive));
+}
+
+#[test]
+fn test_invalid_glyph() {
+    assert_eq!(
+        Field::try_from([[DEAD, DEAD, DEAD], [DEAD, 'x', DEAD], [DEAD, DEAD, ALIVE]]),
+        Err(GolError::InvalidGlyph('x'))
+    );
+}
+
+#[test]
+fn test_length_mismatch() {
+    let field = Field::dead(4, 3);
+    let mut output = [Cell::Dead; 3];
+    let error = GolError::LengthMismatch {
+        expected: 4,
+        actual: 3,
+    };
+    assert_eq!(
+        field.advance_row_into(0, &Rule::default(), Topology::default(), &mut output),
+        Err(error.clone())
+    );
+    assert_eq!(error.to_string(), "expected 4 cells, got 3");
+
+    assert_eq!(
+        Grid::from_cells(3, 3, vec![Cell::Dead; 8]),
+        Err(GolError::LengthMismatch {
+            expected: 9,
+            actual: 8
+        })
+    );
+}
+
+#[test]
+fn test_invalid_state() {
+    assert_eq!(DyingState::new(0), Err(GolError::InvalidState(0)));
+    assert_eq!(DyingState::new(1), Err(GolError::InvalidState(1)));
+    assert_eq!(DyingState::new(2), Ok(DyingState::FIRST));
+    assert_eq!(DyingState::new(255), Err(GolError::InvalidState(255)));
+
+    assert_eq!(Cell::try_from(State(0)), Ok(Cell::Dead));
+    assert_eq!(Cell::try_from(State(1)), Ok(Cell::Alive));
+    assert_eq!(Cell::try_from(State(255)), Err(GolError::InvalidState(255)));
+}
@@ -0,0 +1,41 @@
+use gol_lib::{Cell, DyingState, Field, Grid, State};
+
+#[test]
+fn test_pgm() {
+    let mut field = Field::dead(3, 3);
+    *field.value_mut((0, 0)) = Cell::Alive;
+    *field.value_mut((1, 0)) = Cell::Dying(DyingState::FIRST);
+    *field.value_mut((2, 0)) = Cell::Dying(DyingState::new(3).unwrap());
+
+    let mut image = Vec::new();
+    field.write_pgm(&mut image, 4).unwrap();
+    let header = b"P5\n3 3\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(
+        &image[header.len()..],
+        &[0, 85, 170, 255, 255, 255, 255, 255, 255]
+    );
+}
+
+#[test]
+fn test_pgm_states_exceeding_rule() {
+    // Dying states the rule doesn't have are drawn lighter than any but never as dead.
+    let grid = Grid::from_cells(3, 3, vec![State(9); 9]).unwrap();
+    let mut image = Vec::new();
+    grid.write_pgm(&mut image, 2).unwrap();
+    assert!(image.ends_with(&[254; 9]));
+}
+
+#[test]
+fn test_pgm_continuous() {
+    let grid =
+        Grid::from_cells(3, 3, vec![0.0, 1.0, 0.5, -1.0, 2.0, 0.001, 0.0, 0.0, 0.0]).unwrap();
+    let mut image = Vec::new();
+    grid.write_pgm(&mut image).unwrap();
+    let header = b"P5\n3 3\n255\n";
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(
+        &image[header.len()..],
+        &[255, 0, 127, 255, 0, 253, 255, 255, 255]
+    );
+}
@@ -0,0 +1,147 @@
+use gol_lib::{
+    Cell, ElementaryRule, Field, Grid, Line, LineRule, State, Topology, TotalisticRule, ALIVE, DEAD,
+};
+
+/// Parses `#` as alive and anything else as dead.
+fn line(cells: &str) -> Line {
+    let cells = cells
+        .chars()
+        .map(|glyph| {
+            if glyph == '#' {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            }
+        })
+        .collect();
+    Line::new(cells).unwrap()
+}
+
+#[test]
+fn test_rule_30() {
+    let rule = ElementaryRule::new(30);
+    let field = Line::single(7)
+        .unwrap()
+        .space_time(&rule, Topology::Bounded, 4)
+        .unwrap();
+    let expected = ["...#...", "..###..", ".##..#.", "##.####"];
+    for (y, row) in expected.iter().enumerate() {
+        let row = line(row);
+        for x in 0..7 {
+            assert_eq!(field.value((x, y)), &row.cells()[x], "({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_rule_110() {
+    let rule = "W110".parse::<ElementaryRule>().unwrap();
+    let mut current = line("......#");
+    for expected in [".....##", "....###", "...##.#", "..#####"] {
+        current = current.advance(&rule, Topology::Bounded);
+        assert_eq!(current, line(expected));
+    }
+    assert_eq!(rule.to_string(), "W110");
+    assert!("W256".parse::<ElementaryRule>().is_err());
+    assert!("110".parse::<ElementaryRule>().is_err());
+}
+
+#[test]
+fn test_torus() {
+    let rule = ElementaryRule::new(30);
+    assert_eq!(line("#....").advance(&rule, Topology::Torus), line("##..#"));
+    assert_eq!(
+        line("#....").advance(&rule, Topology::Bounded),
+        line("##...")
+    );
+}
+
+/// Rule 90 draws Pascal's triangle modulo 2.
+#[test]
+fn test_sierpinski() {
+    let field = Line::single(33)
+        .unwrap()
+        .space_time(&ElementaryRule::new(90), Topology::Bounded, 16)
+        .unwrap();
+    for y in 0..16 {
+        for x in 0..33usize {
+            let offset = x as isize - 16;
+            let expected = (offset + y as isize) % 2 == 0 && offset.abs() <= y as isize && {
+                let k = ((offset + y as isize) / 2) as usize;
+                k & y == k
+            };
+            assert_eq!(field.value((x, y)).is_alive(), expected, "({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_totalistic() {
+    // Alive with one or two alive cells among the three, which is elementary Rule 126.
+    let totalistic = TotalisticRule::new(2, 0b0110).unwrap();
+    let elementary = ElementaryRule::new(126);
+    let start = Field::random_seeded(40, 3, 5).unwrap();
+    let start = Line::new((0..40).map(|x| *start.value((x, 0))).collect()).unwrap();
+    let states = Line::new(
+        start
+            .cells()
+            .iter()
+            .map(|&cell| State::from(cell))
+            .collect(),
+    )
+    .unwrap();
+    for topology in [Topology::Bounded, Topology::Torus] {
+        let expected = start.space_time(&elementary, topology, 20).unwrap();
+        assert_eq!(
+            states.space_time(&totalistic, topology, 20),
+            Ok(Grid::from(&expected).map(|&cell| State::from(cell)))
+        );
+    }
+
+    // Three colours, where sums of 1 and 2 give colour 2 and a sum of 3 gives colour 1.
+    let rule = TotalisticRule::new(3, 2 * 3 + 2 * 9 + 27).unwrap();
+    let (dead, alive, two) = (State(0), State(1), State(2));
+    assert_eq!(rule.next(dead, alive, dead), two);
+    assert_eq!(rule.next(alive, alive, dead), two);
+    assert_eq!(rule.next(two, alive, dead), alive);
+    assert_eq!(rule.next(two, two, two), dead);
+
+    assert!(TotalisticRule::new(2, 16).is_err());
+    assert!(TotalisticRule::new(3, 2186).is_ok());
+    assert!(TotalisticRule::new(3, 2187).is_err());
+    assert!(TotalisticRule::new(1, 0).is_err());
+    assert!(TotalisticRule::new(8, 0).is_err());
+}
+
+/// Every colour of a 7-colour rule keeps a glyph of its own.
+#[test]
+fn test_seven_colours() {
+    // The next colour is the sum modulo 7.
+    let code = (0..19).map(|sum| sum % 7 * 7u64.pow(sum as u32)).sum();
+    let rule = TotalisticRule::new(7, code).unwrap();
+    let start = Line::new((0..7).map(State).collect()).unwrap();
+    let diagram = start.space_time(&rule, Topology::Torus, 3).unwrap();
+
+    let expected = ["0123456", "0362514", "0246135"]
+        .iter()
+        .map(|row| {
+            row.replace('0', &DEAD.to_string())
+                .replace('1', &ALIVE.to_string())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(diagram.to_string(), expected.join("\n") + "\n");
+
+    let mut glyphs: Vec<_> = start.to_string().trim_end().chars().collect();
+    glyphs.sort_unstable();
+    glyphs.dedup();
+    assert_eq!(glyphs.len(), 7);
+}
+
+#[test]
+fn test_too_small() {
+    assert!(Line::single(2).is_err());
+    let line = Line::single(5).unwrap();
+    assert!(line
+        .space_time(&ElementaryRule::new(30), Topology::Bounded, 2)
+        .is_err());
+}
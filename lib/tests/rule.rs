@@ -0,0 +1,455 @@
+use gol_lib::{
+    BlockRule, Cell, DyingState, Field, GolError, IsotropicRule, LargerThanLife, Neighbourhood,
+    Rule, Topology, Transition, ALIVE, DEAD,
+};
+use std::convert::TryFrom;
+
+fn dying(state: u8) -> Cell {
+    Cell::Dying(DyingState::new(state).unwrap())
+}
+
+/// One configuration of every letter of Hensel notation, turned a quarter clockwise from the
+/// usual reference drawings. `#` are the alive neighbours around the cell in the middle.
+const HENSEL: [(&str, [&str; 3]); 31] = [
+    ("1c", ["..#", "...", "..."]),
+    ("1e", ["...", "..#", "..."]),
+    ("2c", ["..#", "...", "..#"]),
+    ("2e", [".#.", "..#", "..."]),
+    ("2a", ["..#", "..#", "..."]),
+    ("2i", [".#.", "...", ".#."]),
+    ("2k", ["..#", "...", ".#."]),
+    ("2n", ["#..", "...", "..#"]),
+    ("3c", ["#.#", "...", "..#"]),
+    ("3e", [".#.", "..#", ".#."]),
+    ("3a", [".##", "..#", "..."]),
+    ("3i", ["..#", "..#", "..#"]),
+    ("3k", ["#..", "..#", ".#."]),
+    ("3n", [".##", "...", "..#"]),
+    ("3j", [".#.", "..#", "..#"]),
+    ("3q", ["#..", "..#", "..#"]),
+    ("3r", [".##", "...", ".#."]),
+    ("3y", ["#.#", "...", ".#."]),
+    ("4c", ["#.#", "...", "#.#"]),
+    ("4e", [".#.", "#.#", ".#."]),
+    ("4a", [".##", "..#", "..#"]),
+    ("4i", [".##", "...", ".##"]),
+    ("4k", ["#.#", "..#", ".#."]),
+    ("4n", ["#.#", "..#", "..#"]),
+    ("4j", ["##.", "..#", ".#."]),
+    ("4q", ["#..", "..#", ".##"]),
+    ("4r", [".##", "..#", ".#."]),
+    ("4y", ["#.#", "...", ".##"]),
+    ("4t", ["###", "...", ".#."]),
+    ("4w", ["##.", "..#", "..#"]),
+    ("4z", ["##.", "...", ".##"]),
+];
+
+/// Neighbours of a configuration in reading order.
+fn configuration(rows: &[&str; 3]) -> Vec<Cell> {
+    let glyphs = rows.iter().flat_map(|row| row.chars());
+    glyphs
+        .enumerate()
+        .filter(|&(index, _)| index != 4)
+        .map(|(_, glyph)| {
+            if glyph == '#' {
+                Cell::Alive
+            } else {
+                Cell::Dead
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_rule() {
+    assert_eq!("B3/S23".parse(), Ok(Rule::CONWAY));
+    assert_eq!("b3/s23".parse(), Ok(Rule::CONWAY));
+    assert_eq!(
+        "B36/S23".parse::<Rule>().map(|rule| rule.to_string()),
+        Ok("B36/S23".to_string())
+    );
+    assert_eq!("B/S".parse(), Rule::new(&[], &[]));
+    assert_eq!(
+        "B9/S23".parse::<Rule>(),
+        Err(GolError::InvalidRule(
+            "9 exceeds the 8 neighbours of a cell".to_string()
+        ))
+    );
+    assert!("B3S23".parse::<Rule>().is_err());
+    assert!("B3/S2x".parse::<Rule>().is_err());
+}
+
+#[test]
+fn test_parse_generations() {
+    assert_eq!("B2/S/C3".parse(), Ok(Rule::BRIANS_BRAIN));
+    assert_eq!("b2/s345/c4".parse(), Ok(Rule::STAR_WARS));
+    assert_eq!(Rule::STAR_WARS.to_string(), "B2/S345/C4");
+    assert_eq!("B3/S23/C2".parse(), Ok(Rule::CONWAY));
+    assert_eq!(Rule::CONWAY.states(), 2);
+    assert!("B2/S/C1".parse::<Rule>().is_err());
+    assert!("B2/S/C256".parse::<Rule>().is_err());
+    assert!("B2/S/3".parse::<Rule>().is_err());
+    assert!("B2/S/C3/C3".parse::<Rule>().is_err());
+}
+
+#[test]
+fn test_parse_neighbourhoods() {
+    let von_neumann = "B2/S013V".parse::<Rule>().unwrap();
+    assert_eq!(von_neumann.neighbourhood(), Neighbourhood::VonNeumann(1));
+    assert_eq!(von_neumann.to_string(), "B2/S013V");
+    assert_eq!("b2/s013v".parse(), Ok(von_neumann));
+    assert_eq!(
+        "B2/S34/C5H".parse::<Rule>().map(|rule| rule.to_string()),
+        Ok("B2/S34/C5H".to_string())
+    );
+    assert_eq!(
+        "B5/S4V".parse::<Rule>(),
+        Err(GolError::InvalidRule(
+            "5 exceeds the 4 neighbours of a cell".to_string()
+        ))
+    );
+    assert!("B7/SH".parse::<Rule>().is_err());
+
+    let higher = "R2,C0,S2-3,5,B3,NN".parse::<Rule>().unwrap();
+    assert_eq!(higher.neighbourhood(), Neighbourhood::VonNeumann(2));
+    assert_eq!(higher.to_string(), "R2,C0,S2-3,5,B3,NN");
+    assert_eq!(
+        "r5,c3,s,b34-40,nm"
+            .parse::<Rule>()
+            .map(|rule| rule.to_string()),
+        Ok("R5,C3,S,B34-40,NM".to_string())
+    );
+    assert_eq!("R1,C0,S2-3,B3,NM".parse::<Rule>(), Ok(Rule::CONWAY));
+    assert_eq!(
+        Rule::CONWAY.with_neighbourhood(Neighbourhood::Moore(2)),
+        "R2,C0,S2-3,B3,NM".parse()
+    );
+    assert!("R2,C0,S2-3,B13,NN".parse::<Rule>().is_err());
+    assert!("R2,C0,S2-3,NM".parse::<Rule>().is_err());
+    assert!("R0,C0,S,B,NM".parse::<Rule>().is_err());
+    assert!("R6,C0,S,B,NM".parse::<Rule>().is_err());
+    // Huge ranges of counts are rejected before they are listed.
+    assert_eq!(
+        "R2,C0,S2-18446744073709551615,B3,NM".parse::<Rule>(),
+        Err(GolError::InvalidRule(
+            "18446744073709551615 exceeds the 127 neighbours a rule can count".to_string()
+        ))
+    );
+    assert!("R2,C1,S,B,NM".parse::<Rule>().is_err());
+    assert!("R2,C0,S,B,NX".parse::<Rule>().is_err());
+}
+
+#[test]
+fn test_neighbourhood_sizes() {
+    assert_eq!(Neighbourhood::default().size(), 8);
+    assert_eq!(Neighbourhood::Moore(2).size(), 24);
+    assert_eq!(
+        Neighbourhood::VonNeumann(1).offsets(),
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+    );
+    assert_eq!(Neighbourhood::VonNeumann(2).size(), 12);
+    assert_eq!(
+        Neighbourhood::Hexagonal.offsets(),
+        [(-1, -1), (0, -1), (-1, 0), (1, 0), (0, 1), (1, 1)]
+    );
+}
+
+#[test]
+fn test_generations() {
+    let rule = Rule::STAR_WARS;
+    assert_eq!(rule.next(Cell::Dead, 2), Cell::Alive);
+    assert_eq!(rule.next(Cell::Alive, 3), Cell::Alive);
+    assert_eq!(rule.next(Cell::Alive, 2), dying(2));
+    // Dying cells neither survive nor are born again.
+    assert_eq!(rule.next(dying(2), 3), dying(3));
+    assert_eq!(rule.next(dying(3), 2), Cell::Dead);
+    assert_eq!(Rule::BRIANS_BRAIN.next(dying(2), 2), Cell::Dead);
+}
+
+#[test]
+fn test_conway() {
+    assert_eq!(Rule::CONWAY.next(Cell::Dead, 3), Cell::Alive);
+    assert_eq!(Rule::CONWAY.next(Cell::Dead, 2), Cell::Dead);
+    assert_eq!(Rule::CONWAY.next(Cell::Alive, 1), Cell::Dead);
+    assert_eq!(Rule::CONWAY.next(Cell::Alive, 2), Cell::Alive);
+    assert_eq!(Rule::CONWAY.next(Cell::Alive, 4), Cell::Dead);
+}
+
+#[test]
+fn test_torus() {
+    // A blinker wrapping around the left and right edge.
+    let field = Field::try_from([
+        [DEAD, DEAD, DEAD, DEAD, DEAD],
+        [DEAD, DEAD, DEAD, DEAD, DEAD],
+        [ALIVE, ALIVE, DEAD, DEAD, ALIVE],
+        [DEAD, DEAD, DEAD, DEAD, DEAD],
+        [DEAD, DEAD, DEAD, DEAD, DEAD],
+    ])
+    .unwrap();
+
+    assert_eq!(
+        field.advance_one_with((0, 1), &Rule::CONWAY, Topology::Torus),
+        Ok(Some(Cell::Alive))
+    );
+    assert_eq!(
+        field.advance_one_with((4, 2), &Rule::CONWAY, Topology::Torus),
+        Ok(Some(Cell::Dead))
+    );
+    assert_eq!(
+        field.advance_one_with((0, 1), &Rule::CONWAY, Topology::Bounded),
+        Ok(None)
+    );
+}
+
+#[test]
+fn test_neighbourhood() {
+    let field = Field::try_from([
+        [DEAD, DEAD, DEAD, ALIVE],
+        [DEAD, DEAD, ALIVE, DEAD],
+        [DEAD, DEAD, DEAD, DEAD],
+        [ALIVE, DEAD, DEAD, DEAD],
+    ])
+    .unwrap();
+
+    let (a, d) = (Cell::Alive, Cell::Dead);
+    assert_eq!(
+        field.neighbourhood((3, 0), Topology::Bounded),
+        Ok([d, d, d, d, d, a, d, d])
+    );
+    assert_eq!(
+        field.neighbourhood((3, 0), Topology::Torus),
+        Ok([d, d, a, d, d, a, d, d])
+    );
+    assert!(field.neighbourhood((4, 0), Topology::Torus).is_err());
+}
+
+#[test]
+fn test_neighbours_in() {
+    let field = Field::try_from([
+        [DEAD, DEAD, DEAD, ALIVE],
+        [DEAD, DEAD, ALIVE, DEAD],
+        [DEAD, DEAD, DEAD, DEAD],
+        [ALIVE, DEAD, DEAD, DEAD],
+    ])
+    .unwrap();
+
+    let (a, d) = (Cell::Alive, Cell::Dead);
+    assert_eq!(
+        field.neighbours_in((3, 0), Neighbourhood::default(), Topology::Torus),
+        field.neighbourhood((3, 0), Topology::Torus).map(Vec::from)
+    );
+    assert_eq!(
+        field.neighbours_in((2, 0), Neighbourhood::VonNeumann(1), Topology::Torus),
+        Ok(vec![d, d, a, a])
+    );
+    assert_eq!(
+        field.neighbours_in((2, 1), Neighbourhood::Hexagonal, Topology::Bounded),
+        Ok(vec![d, d, d, d, d, d])
+    );
+    // (3, 0) is two columns left and right of (1, 0) on a torus this narrow.
+    let far = field.neighbours_in((1, 0), Neighbourhood::VonNeumann(2), Topology::Torus);
+    let alive = far.map(|cells| cells.into_iter().filter(|cell| cell.is_alive()).count());
+    assert_eq!(alive, Ok(4));
+    assert!(field
+        .neighbours_in((4, 0), Neighbourhood::Hexagonal, Topology::Torus)
+        .is_err());
+}
+
+#[test]
+fn test_display_hex() {
+    let field = Field::try_from([
+        [ALIVE, DEAD, DEAD],
+        [DEAD, ALIVE, DEAD],
+        [DEAD, DEAD, ALIVE],
+    ])
+    .unwrap();
+    let (a, d) = (ALIVE, DEAD);
+    assert_eq!(
+        field.display_hex().to_string(),
+        format!("  {a} {d} {d}\n {d} {a} {d}\n{d} {d} {a}\n", a = a, d = d)
+    );
+}
+
+#[test]
+fn test_parse_isotropic() {
+    let tlife = "B3/S2-i34q".parse::<IsotropicRule>().unwrap();
+    assert_eq!(tlife.to_string(), "B3/S2-i34q");
+    assert_eq!("b3/s2cekan34q".parse::<IsotropicRule>(), Ok(tlife));
+    // All letters of a count are the same as the count alone.
+    assert_eq!(
+        "B2cekain/S".parse::<IsotropicRule>(),
+        "B2/S".parse::<IsotropicRule>()
+    );
+    assert_eq!(
+        "B3/S23"
+            .parse::<IsotropicRule>()
+            .map(|rule| rule.to_string()),
+        Ok("B3/S23".to_string())
+    );
+    assert_eq!(
+        "B2ek6i/S0"
+            .parse::<IsotropicRule>()
+            .map(|rule| rule.to_string()),
+        Ok("B2ek6i/S0".to_string())
+    );
+    assert!("B1k/S".parse::<IsotropicRule>().is_err());
+    assert!("B0c/S".parse::<IsotropicRule>().is_err());
+    assert!("B2-/S".parse::<IsotropicRule>().is_err());
+    assert!("B9/S".parse::<IsotropicRule>().is_err());
+    assert!("B3/S2/C3".parse::<IsotropicRule>().is_err());
+}
+
+#[test]
+fn test_isotropic_transition() {
+    let tlife = "B3/S2-i34q".parse::<IsotropicRule>().unwrap();
+    let (a, d) = (Cell::Alive, Cell::Dead);
+
+    // 2i, the west and east neighbours.
+    assert_eq!(tlife.transition(a, &[d, d, d, a, a, d, d, d]), Cell::Dead);
+    // 2i rotated, the north and south neighbours.
+    assert_eq!(tlife.transition(a, &[d, a, d, d, d, d, a, d]), Cell::Dead);
+    // 2e, the north and west neighbours.
+    assert_eq!(tlife.transition(a, &[d, a, d, a, d, d, d, d]), Cell::Alive);
+    assert_eq!(tlife.transition(d, &[d, a, d, a, d, d, d, a]), Cell::Alive);
+    // 4c, the four corners.
+    assert_eq!(tlife.transition(a, &[a, d, a, d, d, a, d, a]), Cell::Dead);
+}
+
+#[test]
+fn test_isotropic_letters() {
+    for (name, rows) in HENSEL.iter() {
+        let (count, letter) = name.split_at(1);
+        let count = count.parse::<usize>().unwrap();
+        let neighbours = configuration(rows);
+        let complement = neighbours
+            .iter()
+            .map(|cell| {
+                if cell.is_alive() {
+                    Cell::Dead
+                } else {
+                    Cell::Alive
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut cases = vec![(count, neighbours)];
+        // The complement of a configuration of four can have another letter.
+        if count < 4 {
+            cases.push((8 - count, complement));
+        }
+        for (count, neighbours) in cases {
+            let only = format!("B{}{}/S", count, letter);
+            let except = format!("B{}-{}/S", count, letter);
+            let rule = |rule: &str| rule.parse::<IsotropicRule>().unwrap();
+            assert_eq!(
+                rule(&only).transition(Cell::Dead, &neighbours),
+                Cell::Alive,
+                "{}",
+                only
+            );
+            assert_eq!(
+                rule(&except).transition(Cell::Dead, &neighbours),
+                Cell::Dead,
+                "{}",
+                except
+            );
+        }
+    }
+}
+
+/// tlife keeps every configuration of two alive neighbours but `2i`, all of three and only
+/// `4q` of four.
+#[test]
+fn test_tlife_letters() {
+    let tlife = "B3/S2-i34q".parse::<IsotropicRule>().unwrap();
+    for (name, rows) in HENSEL.iter() {
+        let survives = match *name {
+            "2i" => false,
+            "4q" => true,
+            name => name.starts_with('2') || name.starts_with('3'),
+        };
+        let expected = if survives { Cell::Alive } else { Cell::Dead };
+        let neighbours = configuration(rows);
+        assert_eq!(
+            tlife.transition(Cell::Alive, &neighbours),
+            expected,
+            "{}",
+            name
+        );
+        let born = if name.starts_with('3') {
+            Cell::Alive
+        } else {
+            Cell::Dead
+        };
+        assert_eq!(tlife.transition(Cell::Dead, &neighbours), born, "{}", name);
+    }
+}
+
+#[test]
+fn test_parse_larger_than_life() {
+    let bosco = "R5,C0,M1,S34..58,B34..45,NM";
+    assert_eq!(bosco.parse(), Ok(LargerThanLife::BOSCO));
+    assert_eq!(LargerThanLife::BOSCO.to_string(), bosco);
+    assert_eq!(
+        "r10,c3,s100..200,b80..120,nn"
+            .parse::<LargerThanLife>()
+            .map(|rule| rule.to_string()),
+        Ok("R10,C3,M0,S100..200,B80..120,NN".to_string())
+    );
+    assert_eq!(
+        "R2,S6..10,B7..9".parse(),
+        LargerThanLife::new(Neighbourhood::Moore(2), 7..=9, 6..=10)
+    );
+    assert!("R0,S1..2,B1..1".parse::<LargerThanLife>().is_err());
+    assert!("R2,S6..10".parse::<LargerThanLife>().is_err());
+    assert!("R2,S6,B7..9".parse::<LargerThanLife>().is_err());
+    assert!("R2,M2,S6..10,B7..9".parse::<LargerThanLife>().is_err());
+    assert!("R2,C1,S6..10,B7..9".parse::<LargerThanLife>().is_err());
+    assert!(LargerThanLife::new(Neighbourhood::Hexagonal, 1..=1, 1..=2).is_err());
+}
+
+#[test]
+fn test_larger_than_life_middle() {
+    let rule = "R1,C3,M1,S3..3,B2..2,NM".parse::<LargerThanLife>().unwrap();
+    // The cell itself is part of the count.
+    assert_eq!(rule.next(Cell::Alive, 3), Cell::Alive);
+    assert_eq!(rule.transition(Cell::Alive, &[Cell::Alive; 2]), Cell::Alive);
+    assert_eq!(rule.transition(Cell::Dead, &[Cell::Alive; 2]), Cell::Alive);
+    assert_eq!(rule.transition(Cell::Alive, &[Cell::Alive; 3]), dying(2));
+    assert_eq!(rule.transition(dying(2), &[Cell::Alive; 2]), Cell::Dead);
+}
+
+#[test]
+fn test_block_rules() {
+    for rule in [
+        BlockRule::CRITTERS,
+        BlockRule::TRON,
+        BlockRule::BILLIARD_BALL,
+    ] {
+        assert_eq!(BlockRule::new(rule.table()), Ok(rule));
+        assert_eq!(rule.inverse().inverse(), rule);
+    }
+    assert_eq!(BlockRule::TRON.inverse(), BlockRule::TRON);
+
+    let mut table = BlockRule::TRON.table();
+    table[1] = 2;
+    assert!(BlockRule::new(table).is_err());
+    table[1] = 16;
+    assert!(BlockRule::new(table).is_err());
+}
+
+#[test]
+fn test_check_unbounded() {
+    assert_eq!(Rule::CONWAY.check_unbounded(), Ok(()));
+    assert_eq!(
+        "B0/S".parse::<Rule>().unwrap().check_unbounded(),
+        Err(GolError::InvalidRule(
+            "B0/S gives birth to cells without alive neighbours on an unbounded field".to_string()
+        ))
+    );
+    assert!(Rule::STAR_WARS.check_unbounded().is_err());
+    assert!(Rule::CONWAY
+        .with_neighbourhood(Neighbourhood::VonNeumann(1))
+        .unwrap()
+        .check_unbounded()
+        .is_err());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "serde")]
+
+use gol_lib::{
+    Cell, DyingState, Field, Probability, Rule, Snapshot, Topology, UpdateMode, ALIVE, DEAD,
+};
+use std::convert::TryFrom;
+
+#[test]
+fn test_field_json() {
+    let field = Field::try_from([
+        [DEAD, DEAD, DEAD, DEAD],
+        [DEAD, ALIVE, ALIVE, DEAD],
+        [DEAD, ALIVE, ALIVE, DEAD],
+    ])
+    .unwrap();
+
+    let json = serde_json::to_string(&field).unwrap();
+    assert_eq!(json, r#"{"width":4,"height":3,"cells":"YAY="}"#);
+    assert_eq!(serde_json::from_str::<Field>(&json).unwrap(), field);
+}
+
+#[test]
+fn test_invalid_field() {
+    assert!(serde_json::from_str::<Field>(r#"{"width":4,"height":3,"cells":"YA=="}"#).is_err());
+    assert!(serde_json::from_str::<Field>(r#"{"width":2,"height":3,"cells":"AA=="}"#).is_err());
+}
+
+#[test]
+fn test_dying_cells() {
+    let mut field = Field::dead(4, 3);
+    *field.value_mut((1, 1)) = Cell::Alive;
+    *field.value_mut((2, 1)) = Cell::Dying(DyingState::FIRST);
+    *field.value_mut((3, 2)) = Cell::Dying(DyingState::new(7).unwrap());
+
+    let json = serde_json::to_string(&field).unwrap();
+    assert!(json.contains(r#""states":"#));
+    assert_eq!(serde_json::from_str::<Field>(&json).unwrap(), field);
+
+    let binary = bincode::serialize(&field).unwrap();
+    assert_eq!(bincode::deserialize::<Field>(&binary).unwrap(), field);
+
+    // The bitmap has to agree with the states.
+    assert!(serde_json::from_str::<Field>(
+        r#"{"width":3,"height":3,"cells":"AAA=","states":"AQAAAAAAAAAA"}"#
+    )
+    .is_err());
+    // No rule has as many states as a cell in state 255 would need.
+    assert!(serde_json::from_str::<Field>(
+        r#"{"width":3,"height":3,"cells":"AAA=","states":"/wAAAAAAAAAA"}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn test_snapshot_roundtrip() {
+    let snapshot = Snapshot {
+        field: Field::random_seeded(37, 11, 42).unwrap(),
+        generation: 1234,
+        rule: "B36/S23".parse::<Rule>().unwrap(),
+        topology: Topology::Torus,
+        seed: Some(42),
+        update_mode: UpdateMode::asynchronous(0.25).unwrap(),
+        probabilities: (Probability::new(0.5).unwrap(), Probability::ALWAYS),
+        rng_seed: Some(7),
+    };
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    assert!(json.contains(r#""rule":"B36/S23""#));
+    assert_eq!(serde_json::from_str::<Snapshot>(&json).unwrap(), snapshot);
+
+    let binary = bincode::serialize(&snapshot).unwrap();
+    assert_eq!(bincode::deserialize::<Snapshot>(&binary).unwrap(), snapshot);
+
+    // Snapshots without stochastic updates still load.
+    let field = serde_json::to_string(&snapshot.field).unwrap();
+    let deterministic = format!(
+        r#"{{"field":{},"generation":3,"rule":"B3/S23","topology":"Bounded","seed":null}}"#,
+        field
+    );
+    let loaded = serde_json::from_str::<Snapshot>(&deterministic).unwrap();
+    assert_eq!(loaded.update_mode, UpdateMode::Synchronous);
+    assert_eq!(
+        loaded.probabilities,
+        (Probability::ALWAYS, Probability::ALWAYS)
+    );
+
+    let invalid = json.replace("0.25", "1.25");
+    assert!(serde_json::from_str::<Snapshot>(&invalid).is_err());
+}
@@ -0,0 +1,168 @@
+use gol_lib::{Cell, GolError, Topology, Volume, VolumeRule};
+
+fn cube(origin: (usize, usize, usize), size: (usize, usize, usize)) -> Volume {
+    let mut volume = Volume::dead(size.0, size.1, size.2).unwrap();
+    for dz in 0..2 {
+        for dy in 0..2 {
+            for dx in 0..2 {
+                let cords = (
+                    (origin.0 + dx) % size.0,
+                    (origin.1 + dy) % size.1,
+                    (origin.2 + dz) % size.2,
+                );
+                volume.set(cords, Cell::Alive).unwrap();
+            }
+        }
+    }
+    volume
+}
+
+/// Moves every cell of a torus by the given offset.
+fn shift(volume: &Volume, (dx, dy, dz): (usize, usize, usize)) -> Volume {
+    let (width, height, depth) = (volume.width(), volume.height(), volume.depth());
+    let mut shifted = Volume::dead(width, height, depth).unwrap();
+    for (x, y, z) in volume.alive() {
+        let cords = ((x + dx) % width, (y + dy) % height, (z + dz) % depth);
+        shifted.set(cords, Cell::Alive).unwrap();
+    }
+    shifted
+}
+
+#[test]
+fn test_parse_rule() {
+    assert_eq!("B6/S567".parse(), Ok(VolumeRule::LIFE_5766));
+    assert_eq!("b5/s45".parse(), Ok(VolumeRule::LIFE_4555));
+    assert_eq!(VolumeRule::LIFE_5766.to_string(), "B6/S567");
+    assert_eq!(
+        "B5,14/S4,5,26"
+            .parse::<VolumeRule>()
+            .map(|rule| rule.to_string()),
+        Ok("B5,14/S4,5,26".to_string())
+    );
+    let b14_s4 = VolumeRule::new(&[14], &[4]).unwrap();
+    assert_eq!(b14_s4.to_string(), "B14,/S4");
+    assert_eq!(b14_s4.to_string().parse(), Ok(b14_s4));
+    for (birth, survival) in [(&[4][..], &[14][..]), (&[14], &[]), (&[4, 5], &[26])] {
+        let rule = VolumeRule::new(birth, survival).unwrap();
+        assert_eq!(rule.to_string().parse(), Ok(rule), "{}", rule);
+    }
+    assert_eq!(
+        "B6,27/S".parse::<VolumeRule>(),
+        Err(GolError::InvalidRule(
+            "27 exceeds the 26 neighbours of a cell".to_string()
+        ))
+    );
+    assert!("B6,x/S5".parse::<VolumeRule>().is_err());
+    assert!("B6/S5/C3".parse::<VolumeRule>().is_err());
+}
+
+/// Every cell of a 2x2x2 cube has 7 neighbours, and no dead cell has more than 4.
+#[test]
+fn test_cube() {
+    let volume = cube((2, 2, 2), (6, 6, 6));
+    assert_eq!(
+        volume.advance_with(&VolumeRule::LIFE_5766, Topology::Bounded),
+        volume
+    );
+    let dead = Volume::dead(6, 6, 6).unwrap();
+    assert_eq!(
+        volume.advance_with(&VolumeRule::LIFE_4555, Topology::Bounded),
+        dead
+    );
+    let survivor = VolumeRule::new(&[], &[7]).unwrap();
+    assert_eq!(volume.advance_with(&survivor, Topology::Bounded), volume);
+    let birth = VolumeRule::new(&[4], &[]).unwrap();
+    assert_eq!(
+        volume.advance_with(&birth, Topology::Bounded).population(),
+        6 * 4
+    );
+}
+
+#[test]
+fn test_torus() {
+    // The cube wraps around every edge of the torus.
+    let wrapped = cube((4, 4, 4), (5, 5, 5));
+    assert_eq!(
+        wrapped.advance_with(&VolumeRule::LIFE_5766, Topology::Torus),
+        wrapped
+    );
+    assert_eq!(
+        wrapped
+            .advance_with(&VolumeRule::LIFE_5766, Topology::Bounded)
+            .population(),
+        0
+    );
+
+    let rule = VolumeRule::LIFE_4555;
+    let volume = Volume::random_seeded(7, 6, 5, 3).unwrap();
+    let offset = (3, 2, 4);
+    assert_eq!(
+        shift(&volume, offset).advance_with(&rule, Topology::Torus),
+        shift(&volume.advance_with(&rule, Topology::Torus), offset)
+    );
+}
+
+#[test]
+fn test_slice() {
+    let volume = cube((1, 0, 2), (4, 3, 5));
+    let field = volume.slice(3).unwrap();
+    assert_eq!((field.width(), field.height()), (4, 3));
+    for y in 0..3 {
+        for x in 0..4 {
+            assert_eq!(field.value((x, y)), &volume.get((x, y, 3)).unwrap());
+        }
+    }
+    assert_eq!(
+        volume
+            .slice(0)
+            .unwrap()
+            .to_string()
+            .matches(gol_lib::ALIVE)
+            .count(),
+        0
+    );
+    assert_eq!(
+        volume.slice(5),
+        Err(GolError::OutOfVolume {
+            cords: (0, 0, 5),
+            size: (4, 3, 5)
+        })
+    );
+}
+
+#[test]
+fn test_too_small() {
+    assert_eq!(
+        Volume::dead(4, 5, 2),
+        Err(GolError::VolumeTooSmall {
+            width: 4,
+            height: 5,
+            depth: 2
+        })
+    );
+    assert_eq!(
+        GolError::VolumeTooSmall {
+            width: 4,
+            height: 5,
+            depth: 2
+        }
+        .to_string(),
+        "minimum size of a volume is 3x3x3, got 4x5x2"
+    );
+}
+
+#[test]
+fn test_points() {
+    let volume = Volume::random_seeded(5, 4, 3, 8).unwrap();
+    let mut points = Vec::new();
+    volume.write_points(&mut points).unwrap();
+    let lines = String::from_utf8(points.clone()).unwrap();
+    assert_eq!(lines.lines().count(), volume.population());
+    let (x, y, z) = volume.alive().next().unwrap();
+    assert!(lines.starts_with(&format!("{} {} {}\n", x, y, z)));
+
+    assert_eq!(Volume::read_points(5, 4, 3, &points[..]).unwrap(), volume);
+    assert!(Volume::read_points(5, 4, 2, &points[..]).is_err());
+    assert!(Volume::read_points(5, 4, 3, &b"1 2\n"[..]).is_err());
+    assert!(Volume::read_points(5, 4, 3, &b"1 2 3\n"[..]).is_err());
+}
@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use gol_lib::Field;
-use gol_naive::Strategy;
+use gol_lib::{Field, LargerThanLife, Topology};
+use gol_naive::{LargerThanLifeStrategy, Strategy};
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("gol-naive 1000 1000", |b| {
@@ -27,6 +27,13 @@ fn criterion_benchmark(c: &mut Criterion) {
         let mut strategy = Strategy::new(field);
         b.iter(|| strategy.next());
     });
+    c.bench_function("gol-naive larger than life 500 500", |b| {
+        let field = Field::random(500, 500);
+
+        let mut strategy =
+            LargerThanLifeStrategy::new(field, LargerThanLife::BOSCO, Topology::Torus);
+        b.iter(|| strategy.next());
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,52 @@
+use gol_lib::{BlockRule, Field, Topology};
+
+/// Steps a [`Field`] under a reversible [`BlockRule`], alternating the offset of the blocks
+/// every generation, and can step back through the generations exactly.
+pub struct BlockStrategy {
+    field: Field,
+    rule: BlockRule,
+    inverse: BlockRule,
+    topology: Topology,
+    /// Whether the blocks of the next generation start at `(1, 1)`.
+    shifted: bool,
+}
+
+impl BlockStrategy {
+    pub fn new(field: Field, rule: BlockRule, topology: Topology) -> Self {
+        BlockStrategy {
+            field,
+            inverse: rule.inverse(),
+            rule,
+            topology,
+            shifted: false,
+        }
+    }
+
+    /// Returns the previous generation, which also goes back before the field the strategy
+    /// started with.
+    pub fn step_back(&mut self) -> Field {
+        self.shifted = !self.shifted;
+        self.field = self
+            .inverse
+            .advance(&self.field, self.shifted, self.topology);
+        self.field.clone()
+    }
+}
+
+impl Iterator for BlockStrategy {
+    type Item = Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = self.rule.advance(&self.field, self.shifted, self.topology);
+
+        // A field unchanged under one offset may still change under the other one.
+        if field == self.field && self.rule.advance(&field, !self.shifted, self.topology) == field {
+            return None;
+        }
+
+        self.field = field.clone();
+        self.shifted = !self.shifted;
+
+        Some(field)
+    }
+}
@@ -0,0 +1,37 @@
+use gol_lib::{Grid, Topology, Transition};
+
+/// Steps a [`Grid`] of states other than [`Cell`](gol_lib::Cell)s under a [`Transition`] on
+/// them, such as [`Wireworld`](gol_lib::Wireworld) on [`State`](gol_lib::State)s.
+pub struct GridStrategy<T, R> {
+    grid: Grid<T>,
+    rule: R,
+    topology: Topology,
+}
+
+impl<T, R> GridStrategy<T, R> {
+    pub fn new(grid: Grid<T>, rule: R, topology: Topology) -> Self {
+        GridStrategy {
+            grid,
+            rule,
+            topology,
+        }
+    }
+}
+
+impl<T: Copy + Default + PartialEq, R: Transition<T>> Iterator for GridStrategy<T, R> {
+    type Item = Grid<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let grid = match self.grid.advance_with(&self.rule, self.topology) {
+            Ok(grid) => grid?,
+            Err(why) => {
+                eprintln!("Failed to advance grid: {}", why);
+                return None;
+            }
+        };
+
+        self.grid = grid.clone();
+
+        Some(grid)
+    }
+}
@@ -0,0 +1,35 @@
+use gol_lib::{Field, LargerThanLife, Topology};
+
+/// Steps a field under a [`LargerThanLife`] rule, counting all neighbours at once with
+/// summed-area tables instead of cell by cell.
+pub struct LargerThanLifeStrategy {
+    field: Field,
+    rule: LargerThanLife,
+    topology: Topology,
+}
+
+impl LargerThanLifeStrategy {
+    pub fn new(field: Field, rule: LargerThanLife, topology: Topology) -> Self {
+        LargerThanLifeStrategy {
+            field,
+            rule,
+            topology,
+        }
+    }
+}
+
+impl Iterator for LargerThanLifeStrategy {
+    type Item = Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = self.rule.advance(&self.field, self.topology);
+
+        if field == self.field {
+            return None;
+        }
+
+        self.field = field.clone();
+
+        Some(field)
+    }
+}
@@ -1,36 +1,236 @@
-use gol_lib::Field;
+use gol_lib::{Activity, Cell, Field, GolError, Probability, Rule, Snapshot, Topology, Transition};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
-pub struct Strategy {
+mod block;
+mod grid;
+mod larger_than_life;
+mod sparse;
+mod volume;
+
+pub use block::BlockStrategy;
+pub use gol_lib::UpdateMode;
+pub use grid::GridStrategy;
+pub use larger_than_life::LargerThanLifeStrategy;
+pub use sparse::SparseStrategy;
+pub use volume::VolumeStrategy;
+
+/// Advances every cell which may change one at a time, under any [`Transition`] on cells
+/// such as a [`Rule`] or an [`IsotropicRule`](gol_lib::IsotropicRule).
+///
+/// Updates are synchronous and deterministic by default, see [`Strategy::set_update_mode`]
+/// and [`Strategy::set_probabilities`] for stochastic ones.
+pub struct Strategy<T = Rule> {
     field: Field,
+    rule: T,
+    topology: Topology,
+    activity: Activity,
+    mode: UpdateMode,
+    /// Probabilities of a cell being born and of surviving when the rule says so.
+    probabilities: (Probability, Probability),
+    /// Number of generations advanced so far.
+    generation: u64,
+    seed: u64,
+    /// Drawn from anew for every generation, see [`Strategy::set_seed`].
+    rng: StdRng,
 }
 
 impl Strategy {
     pub fn new(field: Field) -> Self {
-        Strategy { field }
+        Self::with_rule(field, Rule::default(), Topology::default())
+    }
+
+    /// Resumes a run from a snapshot, including its stochastic updates.
+    pub fn from_snapshot(snapshot: &Snapshot) -> Self {
+        let mut strategy =
+            Self::with_rule(snapshot.field.clone(), snapshot.rule, snapshot.topology);
+        strategy.generation = snapshot.generation;
+        strategy.set_update_mode(snapshot.update_mode);
+        strategy.probabilities = snapshot.probabilities;
+        if let Some(seed) = snapshot.rng_seed {
+            strategy.set_seed(seed);
+        }
+        strategy
+    }
+
+    /// Snapshot of the current generation which [`Strategy::from_snapshot`] resumes. The seed
+    /// of the initial field isn't known to the strategy and left out.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            generation: self.generation,
+            rule: self.rule,
+            topology: self.topology,
+            update_mode: self.mode,
+            probabilities: self.probabilities,
+            rng_seed: Some(self.seed),
+            ..Snapshot::new(self.field.clone())
+        }
+    }
+}
+
+impl<T: Transition> Strategy<T> {
+    pub fn with_rule(field: Field, rule: T, topology: Topology) -> Self {
+        let range = rule.neighbourhood().range();
+        let activity = Activity::with_range(field.width(), field.height(), topology, range);
+        let seed = rand::random();
+        Strategy {
+            field,
+            rule,
+            topology,
+            activity,
+            mode: UpdateMode::default(),
+            probabilities: (Probability::ALWAYS, Probability::ALWAYS),
+            generation: 0,
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn set_update_mode(&mut self, mode: UpdateMode) {
+        self.mode = mode;
+        self.reset_activity();
+    }
+
+    /// Lets cells the rule gives birth to be born with probability `birth`, and alive cells
+    /// the rule lets survive survive with probability `survival`.
+    pub fn set_probabilities(&mut self, birth: f64, survival: f64) -> Result<(), GolError> {
+        self.probabilities = (Probability::new(birth)?, Probability::new(survival)?);
+        self.reset_activity();
+        Ok(())
+    }
+
+    /// Makes the random choices of stochastic updates reproducible.
+    ///
+    /// Every generation draws from its own generator seeded from `seed` and the number of the
+    /// generation, so a run resumed from a [`Snapshot`] makes the same choices.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+
+    /// Skipping unchanged areas relies on synchronous deterministic updates, so changes
+    /// made under other settings aren't known.
+    fn reset_activity(&mut self) {
+        let range = self.rule.neighbourhood().range();
+        let (width, height) = (self.field.width(), self.field.height());
+        self.activity = Activity::with_range(width, height, self.topology, range);
+    }
+
+    fn is_deterministic(&self) -> bool {
+        self.mode == UpdateMode::Synchronous
+            && self.probabilities == (Probability::ALWAYS, Probability::ALWAYS)
+    }
+
+    /// Decides the next state of a cell the rule takes from `value` to `next`, `None` if the
+    /// cell can't change.
+    fn draw(&mut self, value: Cell, next: Cell) -> Option<Cell> {
+        let (birth, survival) = (self.probabilities.0.get(), self.probabilities.1.get());
+        let drawn = if value.is_alive() && next.is_alive() {
+            if survival >= 1.0 {
+                return None;
+            }
+            if self.rng.gen::<f64>() < survival {
+                value
+            } else {
+                self.rule.death()
+            }
+        } else if next == value {
+            return None;
+        } else if next.is_alive() && self.rng.gen::<f64>() >= birth {
+            value
+        } else {
+            next
+        };
+        match self.mode {
+            UpdateMode::Asynchronous { rate } if self.rng.gen::<f64>() >= rate.get() => Some(value),
+            _ => Some(drawn),
+        }
+    }
+
+    /// Advances all cells under the update mode and probabilities, `None` once no cell can
+    /// change anymore. Generations in which no cell happened to change are still returned.
+    fn next_stochastic(&mut self) -> Option<Field> {
+        let (width, height) = (self.field.width(), self.field.height());
+        let mut cells: Vec<_> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .collect();
+        // Spreads consecutive generations over unrelated seeds.
+        let mixed = self.generation.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        self.rng = StdRng::seed_from_u64(self.seed ^ mixed);
+        let sequential = self.mode == UpdateMode::RandomSequential;
+        if sequential {
+            cells.shuffle(&mut self.rng);
+        }
+
+        let mut field = self.field.clone();
+        let mut changeable = false;
+        for cords in cells {
+            let source = if sequential { &field } else { &self.field };
+            let value = *source.value(cords);
+            let next = match source.advance_one_with(cords, &self.rule, self.topology) {
+                Ok(next) => next.unwrap_or(value),
+                Err(why) => {
+                    eprintln!("Failed to advance cell: {}", why);
+                    return None;
+                }
+            };
+            if let Some(next) = self.draw(value, next) {
+                changeable = true;
+                *field.value_mut(cords) = next;
+            }
+        }
+
+        if !changeable {
+            return None;
+        }
+
+        self.field = field.clone();
+        self.generation += 1;
+
+        Some(field)
     }
 }
 
-impl Iterator for Strategy {
+impl<T: Transition> Iterator for Strategy<T> {
     type Item = Field;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if !self.is_deterministic() {
+            return self.next_stochastic();
+        }
+
         let mut field = self.field.clone();
 
-        let mut updated_any = false;
+        let mut updates = Vec::new();
         for x in 0..self.field.width() {
             for y in 0..self.field.height() {
-                if let Some(value) = self.field.advance_one((x, y)) {
-                    *field.value_mut((x, y)) = value;
-                    updated_any = true;
+                if !self.activity.is_active((x, y)) {
+                    continue;
+                }
+                match self
+                    .field
+                    .advance_one_with((x, y), &self.rule, self.topology)
+                {
+                    Ok(Some(value)) => {
+                        *field.value_mut((x, y)) = value;
+                        updates.push(((x, y), value));
+                    }
+                    Ok(None) => {}
+                    Err(why) => {
+                        eprintln!("Failed to advance cell: {}", why);
+                        return None;
+                    }
                 }
             }
         }
+        self.activity.record(&updates);
 
-        if !updated_any {
+        if updates.is_empty() {
             return None;
         }
 
         self.field = field.clone();
+        self.generation += 1;
 
         Some(field)
     }
@@ -0,0 +1,45 @@
+use gol_lib::{GolError, Rule, SparseField};
+
+/// Steps a [`SparseField`] by only visiting alive cells and their neighbours.
+pub struct SparseStrategy {
+    field: SparseField,
+    rule: Rule,
+}
+
+impl SparseStrategy {
+    pub fn new(field: SparseField) -> Self {
+        SparseStrategy {
+            field,
+            rule: Rule::default(),
+        }
+    }
+
+    /// Fails for rules which aren't supported on an unbounded field, see
+    /// [`Rule::check_unbounded`].
+    pub fn with_rule(field: SparseField, rule: Rule) -> Result<Self, GolError> {
+        rule.check_unbounded()?;
+        Ok(SparseStrategy { field, rule })
+    }
+}
+
+impl Iterator for SparseStrategy {
+    type Item = SparseField;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let field = match self.field.advance_with(&self.rule) {
+            Ok(field) => field,
+            Err(why) => {
+                eprintln!("Failed to advance field: {}", why);
+                return None;
+            }
+        };
+
+        if field == self.field {
+            return None;
+        }
+
+        self.field = field.clone();
+
+        Some(field)
+    }
+}
@@ -0,0 +1,34 @@
+use gol_lib::{Topology, Volume, VolumeRule};
+
+/// Steps a three-dimensional [`Volume`] one generation at a time.
+pub struct VolumeStrategy {
+    volume: Volume,
+    rule: VolumeRule,
+    topology: Topology,
+}
+
+impl VolumeStrategy {
+    pub fn new(volume: Volume, rule: VolumeRule, topology: Topology) -> Self {
+        VolumeStrategy {
+            volume,
+            rule,
+            topology,
+        }
+    }
+}
+
+impl Iterator for VolumeStrategy {
+    type Item = Volume;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let volume = self.volume.advance_with(&self.rule, self.topology);
+
+        if volume == self.volume {
+            return None;
+        }
+
+        self.volume = volume.clone();
+
+        Some(volume)
+    }
+}
@@ -0,0 +1,43 @@
+use gol_lib::{Field, Rule, Topology};
+use gol_naive::Strategy;
+
+/// Evaluates every cell, without skipping stable areas.
+fn advance_all(field: &Field, rule: &Rule, topology: Topology) -> Field {
+    let mut next = field.clone();
+    for row in 0..field.height() {
+        for (cords, value) in field.advance_row_with(row, rule, topology).unwrap() {
+            *next.value_mut(cords) = value;
+        }
+    }
+    next
+}
+
+fn matches_full_evaluation(rule: Rule, topology: Topology) {
+    for seed in 0..4 {
+        let mut field = Field::random_seeded(40, 30, seed).unwrap();
+        let mut strategy = Strategy::with_rule(field.clone(), rule, topology);
+
+        for generation in 1..=60 {
+            let expected = advance_all(&field, &rule, topology);
+            match strategy.next() {
+                Some(next) => assert_eq!(next, expected, "Generation {}", generation),
+                None => {
+                    assert_eq!(field, expected, "Generation {}", generation);
+                    break;
+                }
+            }
+            field = expected;
+        }
+    }
+}
+
+#[test]
+fn test_bounded_soups() {
+    matches_full_evaluation(Rule::CONWAY, Topology::Bounded);
+}
+
+#[test]
+fn test_torus_soups() {
+    matches_full_evaluation(Rule::CONWAY, Topology::Torus);
+    matches_full_evaluation("B36/S23".parse().unwrap(), Topology::Torus);
+}
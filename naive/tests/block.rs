@@ -0,0 +1,56 @@
+use gol_lib::{BlockRule, Cell, Field, Topology};
+use gol_naive::BlockStrategy;
+
+#[test]
+fn test_step_back() {
+    for rule in [
+        BlockRule::CRITTERS,
+        BlockRule::TRON,
+        BlockRule::BILLIARD_BALL,
+    ] {
+        for (topology, width, height) in [
+            (Topology::Torus, 16, 12),
+            (Topology::Torus, 15, 11),
+            (Topology::Bounded, 13, 12),
+        ] {
+            let start = Field::random_seeded(width, height, 5).unwrap();
+            let mut strategy = BlockStrategy::new(start.clone(), rule, topology);
+            let generations: Vec<Field> = strategy.by_ref().take(20).collect();
+            assert_eq!(generations.len(), 20);
+
+            for generation in generations.iter().rev().skip(1) {
+                assert_eq!(strategy.step_back(), *generation);
+            }
+            assert_eq!(strategy.step_back(), start);
+        }
+    }
+}
+
+#[test]
+fn test_step_back_before_start() {
+    let start = Field::random_seeded(8, 8, 1).unwrap();
+    let mut strategy = BlockStrategy::new(start.clone(), BlockRule::CRITTERS, Topology::Torus);
+    let before = strategy.step_back();
+    assert_ne!(before, start);
+    assert_eq!(strategy.next(), Some(start));
+}
+
+#[test]
+fn test_billiard_ball() {
+    let mut field = Field::dead(6, 6);
+    *field.value_mut((0, 0)) = Cell::Alive;
+    let mut strategy = BlockStrategy::new(field, BlockRule::BILLIARD_BALL, Topology::Torus);
+
+    // A single ball keeps moving diagonally, one cell per generation.
+    for step in 1..=4 {
+        let field = strategy.next().unwrap();
+        assert_eq!(*field.value((step, step)), Cell::Alive);
+    }
+}
+
+#[test]
+fn test_empty_billiard_ball() {
+    let mut strategy =
+        BlockStrategy::new(Field::dead(6, 6), BlockRule::BILLIARD_BALL, Topology::Torus);
+    assert_eq!(strategy.next(), None);
+}
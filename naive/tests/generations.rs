@@ -0,0 +1,35 @@
+use gol_lib::{Field, Rule, Topology, ALIVE, DEAD, DYING};
+use gol_naive::Strategy;
+use std::convert::TryFrom;
+
+const D: char = DEAD;
+const A: char = ALIVE;
+const R: char = DYING[0];
+
+#[test]
+fn test_brians_brain() {
+    let field = Field::try_from([[D, D, D, D], [D, A, A, D], [D, D, D, D]]).unwrap();
+    let mut strategy = Strategy::with_rule(field, Rule::BRIANS_BRAIN, Topology::Bounded);
+
+    let first = Field::try_from([[D, A, A, D], [D, R, R, D], [D, A, A, D]]).unwrap();
+    let second = Field::try_from([[D, R, R, D], [A, D, D, A], [D, R, R, D]]).unwrap();
+    assert_eq!(strategy.next(), Some(first));
+    let next = strategy.next().unwrap();
+    assert_eq!(next, second);
+    assert!(next.to_string().contains(R));
+}
+
+#[test]
+fn test_dying_cells_fade() {
+    let field = Field::try_from([[D, D, D], [D, A, D], [D, D, D]]).unwrap();
+    let rule = "B/S/C5".parse::<Rule>().unwrap();
+    let strategy = Strategy::with_rule(field, rule, Topology::Bounded);
+
+    let centres = strategy
+        .map(|field| field.value((1, 1)).glyph())
+        .collect::<String>();
+    assert_eq!(
+        centres,
+        DYING.iter().collect::<String>() + &DEAD.to_string()
+    );
+}
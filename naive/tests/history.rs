@@ -0,0 +1,81 @@
+use gol_lib::{Field, GolError, History, Rule, Topology, ALIVE, DEAD};
+use gol_naive::Strategy;
+use std::convert::TryFrom;
+
+fn glider() -> Field {
+    let mut field = Field::dead(12, 10);
+    let glider = Field::try_from([
+        [DEAD, ALIVE, DEAD],
+        [DEAD, DEAD, ALIVE],
+        [ALIVE, ALIVE, ALIVE],
+    ])
+    .unwrap();
+    for x in 0..3 {
+        for y in 0..3 {
+            *field.value_mut((x, y)) = *glider.value((x, y));
+        }
+    }
+    field
+}
+
+fn strategy(field: Field) -> Strategy {
+    Strategy::with_rule(field, Rule::CONWAY, Topology::Torus)
+}
+
+#[test]
+fn test_seek() {
+    let root = glider();
+    let generations = std::iter::once(root.clone())
+        .chain(strategy(root.clone()))
+        .take(100)
+        .collect::<Vec<_>>();
+
+    let mut history = History::with_limits(root.clone(), strategy(root), 8, usize::MAX);
+    for generation in [37, 5, 20, 21, 99, 0, 64, 63] {
+        let field = history.seek(generation).unwrap();
+        assert_eq!(field, &generations[generation as usize]);
+        assert_eq!(history.generation(), generation);
+    }
+
+    assert_eq!(history.step_back().unwrap(), &generations[62]);
+    assert_eq!(history.next().as_ref(), Some(&generations[63]));
+    assert_eq!(history.latest(), 99);
+}
+
+#[test]
+fn test_memory_budget() {
+    let root = glider();
+    let keyframe = root.width() * root.height();
+    let mut history = History::with_limits(root.clone(), strategy(root), 4, 3 * keyframe);
+
+    history.seek(50).unwrap();
+    assert!(history.earliest() > 0);
+    assert!(matches!(
+        history.seek(0),
+        Err(GolError::OutOfHistory { generation: 0, .. })
+    ));
+    history.seek(history.earliest()).unwrap();
+}
+
+#[test]
+fn test_finished() {
+    let block = Field::try_from([
+        [DEAD, DEAD, DEAD, DEAD],
+        [DEAD, ALIVE, ALIVE, DEAD],
+        [DEAD, ALIVE, ALIVE, DEAD],
+        [DEAD, DEAD, DEAD, DEAD],
+    ])
+    .unwrap();
+    let mut history = History::new(block.clone(), Strategy::new(block.clone()));
+
+    assert_eq!(
+        history.seek(1),
+        Err(GolError::OutOfHistory {
+            generation: 1,
+            earliest: 0,
+            latest: 0
+        })
+    );
+    assert_eq!(history.field(), &block);
+    assert_eq!(history.next(), None);
+}
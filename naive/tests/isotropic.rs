@@ -0,0 +1,19 @@
+use gol_lib::{Field, IsotropicRule, Rule, Topology};
+use gol_naive::Strategy;
+
+#[test]
+fn test_totalistic_rulestrings_match() {
+    for topology in [Topology::Bounded, Topology::Torus] {
+        for rulestring in ["B3/S23", "B36/S23", "B2/S"] {
+            let field = Field::random_seeded(30, 20, 7).unwrap();
+            let rule = rulestring.parse::<Rule>().unwrap();
+            let isotropic = rulestring.parse::<IsotropicRule>().unwrap();
+
+            let totalistic = Strategy::with_rule(field.clone(), rule, topology);
+            let hensel = Strategy::with_rule(field, isotropic, topology);
+            for (generation, (expected, field)) in (1..).zip(totalistic.zip(hensel).take(40)) {
+                assert_eq!(field, expected, "{} generation {}", rulestring, generation);
+            }
+        }
+    }
+}
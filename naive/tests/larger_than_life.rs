@@ -0,0 +1,42 @@
+use gol_lib::{Field, LargerThanLife, Topology};
+use gol_naive::{LargerThanLifeStrategy, Strategy};
+
+/// Summed-area tables give the same generations as counting every neighbour.
+fn matches_enumeration(rule: &str, width: usize, height: usize) {
+    let rule = rule.parse::<LargerThanLife>().unwrap();
+    for topology in [Topology::Bounded, Topology::Torus] {
+        for seed in 0..2 {
+            let field = Field::random_seeded(width, height, seed).unwrap();
+            let tables = LargerThanLifeStrategy::new(field.clone(), rule, topology);
+            let enumeration = Strategy::with_rule(field, rule, topology);
+            for (generation, (tables, enumeration)) in (1..).zip(tables.zip(enumeration)).take(8) {
+                assert_eq!(
+                    tables, enumeration,
+                    "{} {:?} seed {} generation {}",
+                    rule, topology, seed, generation
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_moore() {
+    matches_enumeration("R5,C0,M1,S34..58,B34..45,NM", 30, 25);
+    matches_enumeration("R2,C0,M0,S6..10,B7..9,NM", 20, 15);
+    matches_enumeration("R3,C4,M1,S10..20,B12..16,NM", 20, 15);
+}
+
+#[test]
+fn test_von_neumann() {
+    matches_enumeration("R1,C0,M0,S1..2,B1..1,NN", 12, 10);
+    matches_enumeration("R4,C0,M1,S10..22,B12..18,NN", 20, 15);
+    matches_enumeration("R3,C3,M0,S5..12,B6..9,NN", 20, 15);
+}
+
+/// Ranges beyond the size of the field wrap around a torus more than once.
+#[test]
+fn test_range_larger_than_field() {
+    matches_enumeration("R7,C0,M1,S40..120,B50..90,NM", 6, 5);
+    matches_enumeration("R6,C0,M0,S20..50,B25..40,NN", 6, 5);
+}
@@ -0,0 +1,63 @@
+use gol_lib::{Cell, Field, Rule, Topology};
+use gol_naive::Strategy;
+
+fn single_cell(rule: &str) -> Field {
+    let mut field = Field::dead(5, 5);
+    *field.value_mut((2, 2)) = Cell::Alive;
+    let mut strategy = Strategy::with_rule(field, rule.parse::<Rule>().unwrap(), Topology::Bounded);
+    strategy.next().unwrap()
+}
+
+fn alive(field: &Field) -> Vec<(usize, usize)> {
+    let mut alive = Vec::new();
+    for y in 0..field.height() {
+        for x in 0..field.width() {
+            if field.value((x, y)).is_alive() {
+                alive.push((x, y));
+            }
+        }
+    }
+    alive
+}
+
+#[test]
+fn test_von_neumann() {
+    assert_eq!(
+        alive(&single_cell("B1/SV")),
+        [(2, 1), (1, 2), (3, 2), (2, 3)]
+    );
+}
+
+#[test]
+fn test_hexagonal() {
+    assert_eq!(
+        alive(&single_cell("B1/SH")),
+        [(1, 1), (2, 1), (1, 2), (3, 2), (2, 3), (3, 3)]
+    );
+}
+
+#[test]
+fn test_higher_range() {
+    assert_eq!(alive(&single_cell("R2,C0,S,B1,NN")).len(), 12);
+    assert_eq!(alive(&single_cell("R2,C0,S0,B1,NM")).len(), 25);
+}
+
+/// Skipping unchanged cells has to look as far as the neighbourhood reaches.
+#[test]
+fn test_activity_with_range() {
+    let rule = "R3,C0,S9-18,B11-14,NM".parse::<Rule>().unwrap();
+    for topology in [Topology::Bounded, Topology::Torus] {
+        let mut field = Field::random_seeded(30, 20, 7).unwrap();
+        let strategy = Strategy::with_rule(field.clone(), rule, topology);
+        for (generation, next) in (1..).zip(strategy.take(20)) {
+            let mut expected = field.clone();
+            for row in 0..field.height() {
+                for (cords, cell) in field.advance_row_with(row, &rule, topology).unwrap() {
+                    *expected.value_mut(cords) = cell;
+                }
+            }
+            assert_eq!(next, expected, "{:?} generation {}", topology, generation);
+            field = expected;
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use gol_lib::{Field, ALIVE, DEAD};
 use gol_naive::Strategy;
+use std::convert::TryFrom;
 
 // Tests for a field to oscillate after [steps].
 fn is_oscillating(root: Field, period: usize) {
@@ -20,24 +21,26 @@ fn is_oscillating(root: Field, period: usize) {
 #[test]
 fn test_blinker() {
     is_oscillating(
-        Field::from([
+        Field::try_from([
             [DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, ALIVE, DEAD, DEAD],
             [DEAD, DEAD, ALIVE, DEAD, DEAD],
             [DEAD, DEAD, ALIVE, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD],
-        ]),
+        ])
+        .unwrap(),
         2,
     );
 
     is_oscillating(
-        Field::from([
+        Field::try_from([
             [DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, ALIVE, ALIVE, ALIVE, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD],
-        ]),
+        ])
+        .unwrap(),
         2,
     )
 }
@@ -45,14 +48,15 @@ fn test_blinker() {
 #[test]
 fn test_toad() {
     is_oscillating(
-        Field::from([
+        Field::try_from([
             [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, ALIVE, ALIVE, ALIVE, DEAD],
             [DEAD, ALIVE, ALIVE, ALIVE, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
-        ]),
+        ])
+        .unwrap(),
         2,
     );
 }
@@ -60,14 +64,15 @@ fn test_toad() {
 #[test]
 fn test_beacon() {
     is_oscillating(
-        Field::from([
+        Field::try_from([
             [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
             [DEAD, ALIVE, ALIVE, DEAD, DEAD, DEAD],
             [DEAD, ALIVE, DEAD, DEAD, DEAD, DEAD],
             [DEAD, DEAD, DEAD, DEAD, ALIVE, DEAD],
             [DEAD, DEAD, DEAD, ALIVE, ALIVE, DEAD],
             [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
-        ]),
+        ])
+        .unwrap(),
         2,
     );
 }
@@ -75,7 +80,7 @@ fn test_beacon() {
 #[test]
 fn test_pulsar() {
     is_oscillating(
-        Field::from([
+        Field::try_from([
             [
                 DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD,
                 DEAD, DEAD, DEAD,
@@ -144,7 +149,8 @@ fn test_pulsar() {
                 DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD,
                 DEAD, DEAD, DEAD,
             ],
-        ]),
+        ])
+        .unwrap(),
         3,
     );
 }
@@ -152,7 +158,7 @@ fn test_pulsar() {
 #[test]
 fn test_pentadecathlon() {
     is_oscillating(
-        Field::from([
+        Field::try_from([
             [
                 DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD,
             ],
@@ -207,7 +213,8 @@ fn test_pentadecathlon() {
             [
                 DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD, DEAD,
             ],
-        ]),
+        ])
+        .unwrap(),
         15,
     );
 }
@@ -0,0 +1,92 @@
+use gol_lib::{Cell, DyingState, Field, GolError, SparseField};
+use gol_naive::{SparseStrategy, Strategy};
+use std::convert::TryFrom;
+
+#[test]
+fn test_matches_dense() {
+    // Keep the soup far enough from the edges to never reach them.
+    let soup = Field::random_seeded(20, 20, 3).unwrap();
+    let mut field = Field::dead(160, 160);
+    for x in 0..soup.width() {
+        for y in 0..soup.height() {
+            *field.value_mut((x + 70, y + 70)) = *soup.value((x, y));
+        }
+    }
+
+    let mut dense = Strategy::new(field.clone());
+    let mut sparse = SparseStrategy::new(SparseField::try_from(&field).unwrap());
+    for generation in 1..=60 {
+        let (dense, sparse) = match (dense.next(), sparse.next()) {
+            (Some(dense), Some(sparse)) => (dense, sparse),
+            (None, None) => break,
+            (dense, sparse) => panic!(
+                "Generation {}: dense {:?} but sparse {:?}",
+                generation,
+                dense.is_some(),
+                sparse.is_some()
+            ),
+        };
+        assert_eq!(
+            sparse.to_field((0, 0), 160, 160).unwrap(),
+            dense,
+            "Generation {}",
+            generation
+        );
+    }
+}
+
+#[test]
+fn test_unbounded() {
+    let mut glider = SparseField::new();
+    for cords in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+        glider.set(cords, Cell::Alive).unwrap();
+    }
+
+    // A glider moves by one cell diagonally every 4 generations.
+    let moved = SparseStrategy::new(glider.clone())
+        .nth(4 * 1000 - 1)
+        .unwrap();
+    let mut expected = SparseField::new();
+    for (x, y) in glider.iter() {
+        expected.set((x + 1000, y + 1000), Cell::Alive).unwrap();
+    }
+    assert_eq!(moved, expected);
+}
+
+#[test]
+fn test_unsupported_rule() {
+    let rule = "B0/S".parse().unwrap();
+    assert!(SparseStrategy::with_rule(SparseField::new(), rule).is_err());
+}
+
+#[test]
+fn test_display_far_apart() {
+    let mut field = SparseField::new();
+    field.set((0, 0), Cell::Alive).unwrap();
+    field.set((1, 1), Cell::Alive).unwrap();
+    assert_eq!(field.to_string().lines().count(), 5);
+
+    field.set((1_000_000, 1_000_000), Cell::Alive).unwrap();
+    assert_eq!(
+        field.to_string(),
+        "3 alive cells from (0, 0) to (1000000, 1000000)\n"
+    );
+
+    field.set((i64::MIN, i64::MIN), Cell::Alive).unwrap();
+    field.set((i64::MAX, i64::MAX), Cell::Alive).unwrap();
+    assert!(field.to_string().starts_with("5 alive cells"));
+}
+
+#[test]
+fn test_dying_cells_rejected() {
+    let mut field = Field::dead(4, 4);
+    *field.value_mut((1, 2)) = Cell::Dying(DyingState::FIRST);
+    assert_eq!(
+        SparseField::try_from(&field),
+        Err(GolError::InvalidState(2))
+    );
+    assert_eq!(
+        SparseField::new().set((1, 2), Cell::Dying(DyingState::FIRST)),
+        Err(GolError::InvalidState(2))
+    );
+}
@@ -0,0 +1,166 @@
+use gol_lib::{Cell, DyingState, Field, GolError, Rule, Topology};
+use gol_naive::{Strategy, UpdateMode};
+
+fn dying(state: u8) -> Cell {
+    Cell::Dying(DyingState::new(state).unwrap())
+}
+
+fn strategy(seed: u64) -> Strategy {
+    let field = Field::random_seeded(16, 16, 7).unwrap();
+    let mut strategy = Strategy::with_rule(field, Rule::CONWAY, Topology::Torus);
+    strategy.set_seed(seed);
+    strategy
+}
+
+#[test]
+fn test_certain_updates_are_synchronous() {
+    let synchronous: Vec<Field> = strategy(0).take(10).collect();
+
+    let mut certain = strategy(0);
+    certain.set_probabilities(1.0, 1.0).unwrap();
+    certain.set_update_mode(UpdateMode::asynchronous(1.0).unwrap());
+    assert_eq!(certain.take(10).collect::<Vec<_>>(), synchronous);
+}
+
+#[test]
+fn test_seeded_reproducible() {
+    let run = || {
+        let mut strategy = strategy(42);
+        strategy.set_probabilities(0.8, 0.9).unwrap();
+        strategy.set_update_mode(UpdateMode::asynchronous(0.5).unwrap());
+        strategy.take(10).collect::<Vec<_>>()
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn test_no_births() {
+    let start = Field::random_seeded(16, 16, 7).unwrap();
+    let mut strategy = strategy(1);
+    strategy.set_probabilities(0.0, 1.0).unwrap();
+    for field in strategy.take(10) {
+        for y in 0..16 {
+            for x in 0..16 {
+                if field.value((x, y)).is_alive() {
+                    assert!(start.value((x, y)).is_alive());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_no_survival() {
+    let mut strategy = strategy(1);
+    strategy.set_probabilities(0.0, 0.0).unwrap();
+    let field = strategy.next().unwrap();
+    assert_eq!(field, Field::dead(16, 16));
+    assert_eq!(strategy.next(), None);
+}
+
+#[test]
+fn test_no_rate() {
+    let start = Field::random_seeded(16, 16, 7).unwrap();
+    let mut strategy = strategy(1);
+    strategy.set_update_mode(UpdateMode::asynchronous(0.0).unwrap());
+    assert_eq!(strategy.next(), Some(start));
+}
+
+#[test]
+fn test_asynchronous_rate() {
+    let start = Field::random_seeded(16, 16, 7).unwrap();
+    let next = strategy(0).next().unwrap();
+
+    let mut strategy = strategy(3);
+    strategy.set_update_mode(UpdateMode::asynchronous(0.5).unwrap());
+    let field = strategy.next().unwrap();
+
+    let (mut changes, mut updated) = (0, 0);
+    for y in 0..16 {
+        for x in 0..16 {
+            let value = *field.value((x, y));
+            assert!(value == *start.value((x, y)) || value == *next.value((x, y)));
+            if start.value((x, y)) != next.value((x, y)) {
+                changes += 1;
+                if value == *next.value((x, y)) {
+                    updated += 1;
+                }
+            }
+        }
+    }
+    assert!(updated > changes / 4 && updated < changes * 3 / 4);
+}
+
+#[test]
+fn test_random_sequential() {
+    let synchronous = strategy(0).next().unwrap();
+    let differs = (0..10).any(|seed| {
+        let mut strategy = strategy(seed);
+        strategy.set_update_mode(UpdateMode::RandomSequential);
+        strategy.next().unwrap() != synchronous
+    });
+    assert!(differs);
+}
+
+#[test]
+fn test_failed_survival_decays() {
+    let mut field = Field::dead(5, 5);
+    for x in 1..4 {
+        *field.value_mut((x, 2)) = Cell::Alive;
+    }
+    *field.value_mut((2, 1)) = Cell::Alive;
+    let mut strategy = Strategy::with_rule(field, Rule::STAR_WARS, Topology::Bounded);
+    strategy.set_probabilities(1.0, 0.0).unwrap();
+    let field = strategy.next().unwrap();
+    assert_eq!(*field.value((2, 2)), dying(2));
+}
+
+#[test]
+fn test_invalid_probabilities() {
+    let mut strategy = strategy(0);
+    assert_eq!(
+        strategy.set_probabilities(1.5, 1.0),
+        Err(GolError::InvalidProbability("1.5".to_string()))
+    );
+    assert_eq!(
+        strategy.set_probabilities(1.0, -0.1),
+        Err(GolError::InvalidProbability("-0.1".to_string()))
+    );
+    assert!(strategy.set_probabilities(f64::NAN, 1.0).is_err());
+    assert_eq!(
+        UpdateMode::asynchronous(2.0),
+        Err(GolError::InvalidProbability("2".to_string()))
+    );
+    assert!(UpdateMode::asynchronous(f64::NAN).is_err());
+
+    // Rejected settings leave the updates deterministic.
+    let field = Field::random_seeded(20, 20, 4).unwrap();
+    let deterministic = Strategy::new(field.clone());
+    let mut rejected = Strategy::new(field);
+    assert!(rejected.set_probabilities(0.5, 2.0).is_err());
+    assert_eq!(
+        rejected.take(10).collect::<Vec<_>>(),
+        deterministic.take(10).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_resume_random_sequential() {
+    let mut uninterrupted = strategy(9);
+    uninterrupted.set_update_mode(UpdateMode::RandomSequential);
+    uninterrupted.set_probabilities(0.9, 0.95).unwrap();
+    let mut interrupted = strategy(9);
+    interrupted.set_update_mode(UpdateMode::RandomSequential);
+    interrupted.set_probabilities(0.9, 0.95).unwrap();
+
+    let expected = uninterrupted.take(20).collect::<Vec<_>>();
+    let before = interrupted.by_ref().take(8).collect::<Vec<_>>();
+    let snapshot = interrupted.snapshot();
+    assert_eq!(snapshot.generation, 8);
+    assert_eq!(snapshot.update_mode, UpdateMode::RandomSequential);
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let resumed = Strategy::from_snapshot(&serde_json::from_str(&json).unwrap());
+    let after = resumed.take(12).collect::<Vec<_>>();
+    assert_eq!([before, after].concat(), expected);
+}
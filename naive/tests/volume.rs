@@ -0,0 +1,24 @@
+use gol_lib::{Cell, Topology, Volume, VolumeRule};
+use gol_naive::VolumeStrategy;
+
+#[test]
+fn test_volume_strategy() {
+    let mut volume = Volume::dead(4, 4, 4).unwrap();
+    for cords in [(1, 1, 1), (2, 1, 1), (1, 2, 1), (2, 2, 1)] {
+        volume.set(cords, Cell::Alive).unwrap();
+    }
+    // Each cell of the square has 3 neighbours, cells above and below it have 4.
+    let rule = VolumeRule::new(&[4], &[]).unwrap();
+    let mut strategy = VolumeStrategy::new(volume, rule, Topology::Bounded);
+
+    let next = strategy.next().unwrap();
+    assert_eq!(next.population(), 8);
+    assert!(next.get((1, 1, 0)).unwrap().is_alive());
+    assert!(next.get((2, 2, 2)).unwrap().is_alive());
+
+    // Cells of the squares have 3 neighbours and those between them 8, which neither
+    // survive nor are born under 5766.
+    let mut strategy = VolumeStrategy::new(next, VolumeRule::LIFE_5766, Topology::Bounded);
+    assert_eq!(strategy.next().map(|volume| volume.population()), Some(0));
+    assert_eq!(strategy.next(), None);
+}
@@ -0,0 +1,81 @@
+use gol_lib::{Grid, State, Topology, Wireworld, ALIVE, DEAD};
+use gol_naive::GridStrategy;
+
+/// Parses `.` as empty, `#` as conductor, `H` as electron head and `t` as electron tail.
+fn circuit(rows: &[&str]) -> Grid<State> {
+    let mut grid = Grid::filled(rows[0].len(), rows.len(), Wireworld::EMPTY).unwrap();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, symbol) in row.chars().enumerate() {
+            *grid.value_mut((x, y)) = match symbol {
+                '#' => Wireworld::CONDUCTOR,
+                'H' => Wireworld::HEAD,
+                't' => Wireworld::TAIL,
+                _ => Wireworld::EMPTY,
+            };
+        }
+    }
+    grid
+}
+
+/// Generations within the first `generations` in which an electron head is at `cords`.
+fn signals(grid: Grid<State>, cords: (usize, usize), generations: usize) -> Vec<usize> {
+    let strategy = GridStrategy::new(grid, Wireworld, Topology::Bounded);
+    (1..)
+        .zip(strategy.take(generations))
+        .filter(|(_, grid)| *grid.value(cords) == Wireworld::HEAD)
+        .map(|(generation, _)| generation)
+        .collect()
+}
+
+#[test]
+fn test_diode() {
+    let forward = circuit(&["....##.....", "tH###.#####", "....##....."]);
+    assert_eq!(signals(forward, (10, 1), 30), vec![9]);
+
+    let backward = circuit(&["....##.....", "#####.###Ht", "....##....."]);
+    assert_eq!(signals(backward, (0, 1), 30), Vec::<usize>::new());
+}
+
+#[test]
+fn test_clock() {
+    let clock = circuit(&[".tH#.", "#...#", "#...#", ".###."]);
+    let mut strategy = GridStrategy::new(clock.clone(), Wireworld, Topology::Bounded);
+    assert_eq!(strategy.nth(9), Some(clock));
+
+    let emitter = circuit(&[".tH#.......", "#...#######", "#...#......", ".###......."]);
+    assert_eq!(signals(emitter, (10, 1), 40), vec![8, 18, 28, 38]);
+}
+
+#[test]
+fn test_transitions() {
+    use gol_lib::Transition;
+
+    let heads = [Wireworld::HEAD; 3];
+    assert_eq!(Wireworld.transition(Wireworld::HEAD, &[]), Wireworld::TAIL);
+    assert_eq!(
+        Wireworld.transition(Wireworld::TAIL, &heads),
+        Wireworld::CONDUCTOR
+    );
+    assert_eq!(
+        Wireworld.transition(Wireworld::CONDUCTOR, &heads[..2]),
+        Wireworld::HEAD
+    );
+    assert_eq!(
+        Wireworld.transition(Wireworld::CONDUCTOR, &heads),
+        Wireworld::CONDUCTOR
+    );
+    assert_eq!(
+        Wireworld.transition(Wireworld::EMPTY, &heads[..2]),
+        Wireworld::EMPTY
+    );
+}
+
+#[test]
+fn test_display() {
+    let grid = circuit(&["tH#", "...", "..."]);
+    let empty: String = [DEAD; 3].iter().collect();
+    assert_eq!(
+        grid.to_string(),
+        format!("2{}3\n{}\n{}\n", ALIVE, empty, empty)
+    );
+}
@@ -0,0 +1,68 @@
+use gol_lib::{Field, GolError, Rule, Topology};
+use rayon::prelude::*;
+
+/// Rows per band for every thread, so faster threads can take over bands of slower ones.
+const BANDS_PER_THREAD: usize = 4;
+
+pub struct Strategy {
+    field: Field,
+    /// Preallocated field the next generation is written into.
+    back: Field,
+    rule: Rule,
+    topology: Topology,
+}
+
+impl Strategy {
+    pub fn new(field: Field) -> Self {
+        Self::with_rule(field, Rule::default(), Topology::default())
+    }
+
+    pub fn with_rule(field: Field, rule: Rule, topology: Topology) -> Self {
+        let back = field.clone();
+        Strategy {
+            field,
+            back,
+            rule,
+            topology,
+        }
+    }
+}
+
+impl Iterator for Strategy {
+    type Item = Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let band = (self.field.height() / (rayon::current_num_threads() * BANDS_PER_THREAD)).max(1);
+        let (front, rule, topology) = (&self.field, &self.rule, self.topology);
+
+        let changed = match self
+            .back
+            .rows_mut()
+            .par_chunks_mut(band)
+            .enumerate()
+            .map(|(index, rows)| {
+                let mut changed = false;
+                for (offset, row) in rows.iter_mut().enumerate() {
+                    changed |=
+                        front.advance_row_into(index * band + offset, rule, topology, row)?;
+                }
+                Ok::<_, GolError>(changed)
+            })
+            .try_reduce(|| false, |left, right| Ok(left || right))
+        {
+            Ok(changed) => changed,
+            Err(why) => {
+                eprintln!("Failed to advance rows: {}", why);
+                return None;
+            }
+        };
+
+        if !changed {
+            return None;
+        }
+
+        std::mem::swap(&mut self.field, &mut self.back);
+
+        Some(self.field.clone())
+    }
+}
@@ -0,0 +1,84 @@
+use gol_lib::{Field, ALIVE, DEAD};
+use gol_rayon::Strategy;
+use std::convert::TryFrom;
+
+fn is_still(field: Field) {
+    let mut strategy = Strategy::new(field);
+
+    let next = strategy.next();
+    assert!(
+        next.is_none(),
+        "Advanced on still life to:\n{}",
+        next.unwrap()
+    );
+}
+
+#[test]
+fn test_block() {
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD],
+            [DEAD, ALIVE, ALIVE, DEAD],
+            [DEAD, ALIVE, ALIVE, DEAD],
+            [DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_beehive() {
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_loaf() {
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_boat() {
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, ALIVE, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
+}
+
+#[test]
+fn test_tub() {
+    is_still(
+        Field::try_from([
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, ALIVE, DEAD, ALIVE, DEAD],
+            [DEAD, DEAD, ALIVE, DEAD, DEAD],
+            [DEAD, DEAD, DEAD, DEAD, DEAD],
+        ])
+        .unwrap(),
+    );
+}